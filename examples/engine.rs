@@ -0,0 +1,33 @@
+use ego::{Engine, Value};
+
+fn main() {
+    let mut engine = Engine::new();
+    engine.register_native("double", Some(1), |args| match &args[0] {
+        Value::Number(n) => Ok(Value::Number(n * 2.0)),
+        _ => Ok(Value::Nothing),
+    });
+    engine.set_global("name", Value::from("ego"));
+
+    let code = r#"
+        fn greet(who) {
+            print("Hi, " + who)
+            return double(21)
+        }
+    "#;
+    if let Err(e) = engine.eval(code) {
+        eprintln!("{e}");
+        return;
+    }
+
+    match engine.call("greet", vec![engine.get_global("name").unwrap()]) {
+        Ok(value) => println!("greet returned {value}"),
+        Err(e) => eprintln!("{e}"),
+    }
+    for line in engine.take_output() {
+        println!("ego printed: {line}");
+    }
+
+    if let Err(e) = engine.eval("print(undefined)") {
+        println!("recovered from: {e}");
+    }
+}
@@ -1,364 +1,449 @@
-use super::lexer_types::{LexerTokenType,LexerToken};
+use std::sync::Arc;
+
+use super::lexer_types::{LexerToken, LexerTokenType};
+use crate::core::{
+    error::{EgoError, ErrorType},
+    span::{SourceFile, Span},
+};
 use regex::Regex;
 
+// characters that end an identifier, keyword or number
+const DELIMITERS: [char; 24] = [
+    '"', '`', '=', '<', '>', '!', '+', '-', '*', '/', '%', '~', '|', '&', '(', ')', '{', '}', '[',
+    ']', ',', ';', ':', '.',
+];
 
+pub fn lex(source: String, file: &str) -> Result<Vec<LexerToken>, EgoError> {
+    let file = SourceFile::new(file, source);
+    let chars: Vec<(usize, char)> = file.source.char_indices().collect();
+    let tokens = lex_chars(&file, &chars, 0, 1, 1)?;
 
-const KEYWORDS: [&str; 14] = [
-    "fn", "let", "if", "else", "while", "true", "false", "import", "return", "break", "nothing",
-    "string", "number", "bool",
-];
+    // characters that can't start any token
+    if let Some(token) = tokens
+        .iter()
+        .find(|token| token.token_type == LexerTokenType::Unknown)
+    {
+        return Err(EgoError::new(
+            ErrorType::SyntaxError,
+            format!("Unexpected token '{}'", token.value).as_str(),
+            Some(token.span.clone()),
+        ));
+    }
 
-pub fn lex(source: String) -> Vec<LexerToken> {
-    let keywords = KEYWORDS.to_vec();
-    let mut tokens: Vec<LexerToken> = Vec::new();
+    Ok(tokens)
+}
 
-    let mut current_token = String::new();
-    let mut is_string = false; // inside a string flag
-    let mut is_comment = false; // inside a comment
-    let mut is_float = false; // inside a float value
+// tokens of chars[from..], line and col are the position of chars[from]
+fn lex_chars(
+    file: &Arc<SourceFile>,
+    chars: &[(usize, char)],
+    from: usize,
+    mut line: usize,
+    mut col: usize,
+) -> Result<Vec<LexerToken>, EgoError> {
+    let mut tokens: Vec<LexerToken> = Vec::new();
+    let mut current = from; // index in chars
 
-    let mut chars = source.chars().peekable(); // remove leading and trailing whitespaces
-    let mut char_counter = 1; // all module chars number
-    let mut line_char_counter = 1; // current line chars number
-    let mut line_counter = 1; // track current line
+    while current < chars.len() {
+        let c = chars[current].1;
+        let next = chars.get(current + 1).map(|(_, c)| *c);
 
-    while let Some(c) = chars.next() {
-        if is_comment {
-            if c == '\n' {
-                is_comment = false;
-                line_counter += 1;
-                line_char_counter = 0;
+        // number of chars of the token starting at current
+        let token_len = match c {
+            // comments until the end of the line
+            '/' if next == Some('/') => {
+                while current < chars.len() && chars[current].1 != '\n' {
+                    current += 1;
+                    col += 1;
+                }
+                continue;
             }
-        } else if is_string {
-            if c == '"' {
-                current_token.push(c);
-                is_string = false;
-            } else {
-                current_token.push(c);
+            '\n' => {
+                current += 1;
+                line += 1;
+                col = 1;
+                continue;
             }
-        } else if is_float {
-            if c.is_numeric() && chars.peek().is_some_and(|char| char.is_numeric()) {
-                current_token.push(c);
-            } else {
-                current_token.push(c);
-                is_float = false;
+            _ if c.is_whitespace() => {
+                current += 1;
+                col += 1;
+                continue;
             }
-        } else {
-            // normal mode
-            match c {
-                // a quote
-                '"' => {
-                    if current_token.len() > 0 {
-                        tokens.push(token_with_type(
-                            current_token,
-                            line_counter,
-                            line_char_counter,
-                        ));
-                        current_token = String::new();
-                    }
-
-                    current_token.push(c);
-                    is_string = !is_string;
-                }
-                // comments & divide operator
-                '/' => {
-                    if let Some(next) = chars.peek() {
-                        if c == '/' && next == &'/' {
-                            is_comment = true;
-                        } else {
-                            if current_token.len() > 0 {
-                                tokens.push(token_with_type(
-                                    current_token,
-                                    line_counter,
-                                    line_char_counter - 1,
-                                )); // push previous token, - 1 since is the previous
-                                current_token = String::new();
-                            }
-                            tokens.push(token_with_type(
-                                c.to_string(),
-                                line_counter,
-                                line_char_counter,
-                            ));
-                            current_token = String::new();
-                        }
-                    }
-                }
-                // expressions characters
-                '=' => {
-                    if let Some(next) = chars.peek() {
-                        match next {
-                            '=' => {
-                                // for '=='
-                                chars.next(); // Consume the '='
-                                current_token.push(c);
-                                current_token.push('=');
-                                tokens.push(token_with_type(
-                                    current_token.clone(),
-                                    line_counter,
-                                    line_char_counter,
-                                ));
-                                current_token.clear();
-                            }
-                            _ => {
-                                // for '='
-                                current_token.push(c);
-                                tokens.push(token_with_type(
-                                    current_token.clone(),
-                                    line_counter,
-                                    line_char_counter,
-                                ));
-                                current_token.clear();
-                            }
-                        }
-                    }
-                }
-                '>' => {
-                    if let Some(next) = chars.peek() {
-                        match next {
-                            '=' => {
-                                // for '>='
-                                chars.next(); // Consume the '='
-                                current_token.push(c);
-                                current_token.push('=');
-                                tokens.push(token_with_type(
-                                    current_token.clone(),
-                                    line_counter,
-                                    line_char_counter,
-                                ));
-                                current_token.clear();
-                            }
-                            _ => {
-                                // for '>'
-                                current_token.push(c);
-                                tokens.push(token_with_type(
-                                    current_token.clone(),
-                                    line_counter,
-                                    line_char_counter,
-                                ));
-                                current_token.clear();
-                            }
-                        }
-                    }
-                }
-                '<' => {
-                    if let Some(next) = chars.peek() {
-                        match next {
-                            '=' => {
-                                // for '>='
-                                chars.next(); // Consume the '='
-                                current_token.push(c);
-                                current_token.push('=');
-                                tokens.push(token_with_type(
-                                    current_token.clone(),
-                                    line_counter,
-                                    line_char_counter,
-                                ));
-                                current_token.clear();
-                            }
-                            _ => {
-                                // for '>'
-                                current_token.push(c);
-                                tokens.push(token_with_type(
-                                    current_token.clone(),
-                                    line_counter,
-                                    line_char_counter,
-                                ));
-                                current_token.clear();
-                            }
-                        }
-                    }
-                }
-                '!' => {
-                    if let Some(next) = chars.peek() {
-                        match next {
-                            '=' => {
-                                // for '!='
-                                chars.next(); // Consume the '='
-                                current_token.push(c);
-                                current_token.push('=');
-                                tokens.push(token_with_type(
-                                    current_token.clone(),
-                                    line_counter,
-                                    line_char_counter,
-                                ));
-                                current_token.clear();
-                            }
-                            _ => {
-                                // for '!'
-                                current_token.push(c);
-                                tokens.push(token_with_type(
-                                    current_token.clone(),
-                                    line_counter,
-                                    line_char_counter,
-                                ));
-                                current_token.clear();
-                            }
-                        }
-                    }
+            // a string, which can take more than one line
+            '"' | '`' => match string_len(&chars[current..]) {
+                Some(len) => len,
+                None => {
+                    let span = token_span(file, chars, current, chars.len() - current, line, col);
+                    let message = if c == '"' && chars[current..].iter().any(|(_, c)| *c == '{') {
+                        "Unterminated string literal, use '\\{' for a '{' that doesn't start an interpolation"
+                    } else {
+                        "Unterminated string literal"
+                    };
+                    return Err(EgoError::new(ErrorType::SyntaxError, message, Some(span)));
                 }
-                '+' | '-' | '*' | '|' | '&' => {
-                    if current_token.len() > 0 {
-                        tokens.push(token_with_type(
-                            current_token,
-                            line_counter,
-                            line_char_counter - 1,
-                        )); // push previous token, - 1 since is the previous
-                        current_token = String::new();
-                    }
-                    tokens.push(token_with_type(
-                        c.to_string(),
-                        line_counter,
-                        line_char_counter,
-                    ));
-                    current_token = String::new();
+            },
+            // == | >= | <= | !=
+            '=' | '>' | '<' | '!' if next == Some('=') => 2,
+            // match arms
+            '=' if next == Some('>') => 2,
+            // && | || | **
+            '&' | '|' | '*' if next == Some(c) => 2,
+            // integer division, '//' starts a comment
+            '~' if next == Some('/') => 2,
+            // 0..10
+            '.' if next == Some('.') => 2,
+            _ if DELIMITERS.contains(&c) => 1,
+            // 12 | 12.5
+            _ if c.is_numeric() => {
+                let digits = |from: usize| {
+                    chars[from..]
+                        .iter()
+                        .take_while(|(_, c)| c.is_numeric())
+                        .count()
+                };
+                let integer = digits(current);
+                let dot = current + integer;
+                if chars.get(dot).is_some_and(|(_, c)| *c == '.') && digits(dot + 1) > 0 {
+                    integer + 1 + digits(dot + 1)
+                } else {
+                    integer
                 }
-                // special characters
-                '(' | ')' | '{' | '}' | '[' | ']' | ',' | ';' | ':' => {
-                    if current_token.len() > 0 {
-                        tokens.push(token_with_type(
-                            current_token,
-                            line_counter,
-                            line_char_counter - 1,
-                        )); // push previous token, - 1 since is the previous
-                        current_token = String::new();
-                    }
-                    // push current
-                    tokens.push(token_with_type(
-                        c.to_string(),
-                        line_counter,
-                        line_char_counter,
-                    ));
+            }
+            // identifiers and keywords
+            _ => chars[current..]
+                .iter()
+                .take_while(|(_, c)| !c.is_whitespace() && !DELIMITERS.contains(c))
+                .count(),
+        };
+
+        let span = token_span(file, chars, current, token_len, line, col);
+        let value = file.source[span.start..span.end].to_string();
+        match c {
+            '"' => tokens.extend(string_tokens(file, chars, current, token_len, line, col)?),
+            // raw strings are taken as written
+            '`' => {
+                let content = value[1..value.len() - 1].to_string();
+                tokens.push(LexerToken::new(
+                    LexerTokenType::StringLiteral,
+                    content,
+                    span,
+                ));
+            }
+            _ => tokens.push(token_with_type(value, span)),
+        }
+
+        for (_, c) in &chars[current..current + token_len] {
+            advance(*c, &mut line, &mut col);
+        }
+        current += token_len;
+    }
+
+    Ok(tokens)
+}
+
+fn advance(c: char, line: &mut usize, col: &mut usize) {
+    if c == '\n' {
+        *line += 1;
+        *col = 1;
+    } else {
+        *col += 1;
+    }
+}
+
+// byte range of the chars between from and from + len
+fn token_span(
+    file: &Arc<SourceFile>,
+    chars: &[(usize, char)],
+    from: usize,
+    len: usize,
+    line: usize,
+    col: usize,
+) -> Span {
+    let start = chars[from].0;
+    let end = match len {
+        0 => start,
+        _ => {
+            let (index, c) = chars[from + len - 1];
+            index + c.len_utf8()
+        }
+    };
+    Span::new(file.clone(), start, end, line, col)
+}
+
+fn token_with_type(token: String, span: Span) -> LexerToken {
+    match token.as_str() {
+        "import" => LexerToken::new(LexerTokenType::ImportKeyword, token, span),
+        "fn" => LexerToken::new(LexerTokenType::FnKeyword, token, span),
+        "struct" => LexerToken::new(LexerTokenType::StructKeyword, token, span),
+        "impl" => LexerToken::new(LexerTokenType::ImplKeyword, token, span),
+        "enum" => LexerToken::new(LexerTokenType::EnumKeyword, token, span),
+        "match" => LexerToken::new(LexerTokenType::MatchKeyword, token, span),
+        "while" => LexerToken::new(LexerTokenType::WhileKeyword, token, span),
+        "for" => LexerToken::new(LexerTokenType::ForKeyword, token, span),
+        "in" => LexerToken::new(LexerTokenType::InKeyword, token, span),
+        "let" => LexerToken::new(LexerTokenType::LetKeyword, token, span),
+        "const" => LexerToken::new(LexerTokenType::ConstKeyword, token, span),
+        "if" => LexerToken::new(LexerTokenType::IfKeyword, token, span),
+        "else" => LexerToken::new(LexerTokenType::ElseKeyword, token, span),
+        "true" => LexerToken::new(LexerTokenType::TrueKeyword, token, span),
+        "false" => LexerToken::new(LexerTokenType::FalseKeyword, token, span),
+        "return" => LexerToken::new(LexerTokenType::ReturnKeyword, token, span),
+        "break" => LexerToken::new(LexerTokenType::BreakKeyword, token, span),
+        "continue" => LexerToken::new(LexerTokenType::ContinueKeyword, token, span),
+        "nothing" => LexerToken::new(LexerTokenType::NothingKeyword, token, span),
+        "string" => LexerToken::new(LexerTokenType::StringKeyword, token, span),
+        "number" => LexerToken::new(LexerTokenType::NumberKeyword, token, span),
+        "bool" => LexerToken::new(LexerTokenType::BoolKeyword, token, span),
+        "(" => LexerToken::new(LexerTokenType::OpenParenthesis, token, span),
+        ")" => LexerToken::new(LexerTokenType::CloseParenthesis, token, span),
+        "{" => LexerToken::new(LexerTokenType::OpenCurlyBrace, token, span),
+        "}" => LexerToken::new(LexerTokenType::CloseCurlyBrace, token, span),
+        "[" => LexerToken::new(LexerTokenType::OpenSquareBracket, token, span),
+        "]" => LexerToken::new(LexerTokenType::CloseSquareBracket, token, span),
+        "." => LexerToken::new(LexerTokenType::Dot, token, span),
+        ".." => LexerToken::new(LexerTokenType::RangeOperator, token, span),
+        ":" => LexerToken::new(LexerTokenType::Colon, token, span),
+        "," => LexerToken::new(LexerTokenType::Comma, token, span),
+        ";" => LexerToken::new(LexerTokenType::EndOfStatement, token, span),
+        "!" => LexerToken::new(LexerTokenType::NotOperator, token, span),
+        "!=" => LexerToken::new(LexerTokenType::NotEqualOperator, token, span),
+        "=" => LexerToken::new(LexerTokenType::AssignmentOperator, token, span),
+        "=>" => LexerToken::new(LexerTokenType::FatArrow, token, span),
+        "==" => LexerToken::new(LexerTokenType::EqualityOperator, token, span),
+        "+" => LexerToken::new(LexerTokenType::AddOperator, token, span),
+        "-" => LexerToken::new(LexerTokenType::SubtractOperator, token, span),
+        "*" => LexerToken::new(LexerTokenType::MultiplyOperator, token, span),
+        "**" => LexerToken::new(LexerTokenType::PowerOperator, token, span),
+        "%" => LexerToken::new(LexerTokenType::ModuloOperator, token, span),
+        "~/" => LexerToken::new(LexerTokenType::IntDivideOperator, token, span),
+        "||" => LexerToken::new(LexerTokenType::OrOperator, token, span),
+        "&&" => LexerToken::new(LexerTokenType::AndOperator, token, span),
+        "/" => LexerToken::new(LexerTokenType::DivideOperator, token, span),
+        ">" => LexerToken::new(LexerTokenType::GreaterThanOperator, token, span),
+        ">=" => LexerToken::new(LexerTokenType::GreaterThanOrEqualOperator, token, span),
+        "<" => LexerToken::new(LexerTokenType::LessThanOperator, token, span),
+        "<=" => LexerToken::new(LexerTokenType::LessThanOrEqualOperator, token, span),
+        _ if is_number(&token) => LexerToken::new(LexerTokenType::Number, token, span),
+        _ if is_identifier(&token) => LexerToken::new(LexerTokenType::Identifier, token, span),
+        _ => LexerToken::new(LexerTokenType::Unknown, token, span),
+    }
+}
+
+// length of the string starting at chars[0] with its quotes,
+// `raw strings` have no escapes or interpolations
+fn string_len(chars: &[(usize, char)]) -> Option<usize> {
+    let quote = chars[0].1;
+    let mut depth = 0; // open interpolations
+    let mut current = 1;
+    while current < chars.len() {
+        match chars[current].1 {
+            '\\' if quote == '"' && depth == 0 => current += 2,
+            '{' if quote == '"' => {
+                depth += 1;
+                current += 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                current += 1;
+            }
+            // strings inside an interpolation
+            '"' | '`' if depth > 0 => current += string_len(&chars[current..])?,
+            c if c == quote => return Some(current + 1),
+            _ => current += 1,
+        }
+    }
+    None
+}
+
+// index of the '}' closing the interpolation opened at chars[open]
+fn interpolation_end(chars: &[(usize, char)], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut current = open;
+    while current < chars.len() {
+        match chars[current].1 {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(current);
                 }
-                // dot and float
-                '.' => {
-                    if current_token.chars().all(|char| char.is_numeric()) {
-                        current_token.push(c);
-                        is_float = !is_float;
-                    } else {
-                        tokens.push(token_with_type(
-                            current_token,
-                            line_counter,
-                            line_char_counter,
+            }
+            '"' | '`' => {
+                current += string_len(&chars[current..])?;
+                continue;
+            }
+            _ => {}
+        }
+        current += 1;
+    }
+    None
+}
+
+// "Hello {name}!" is split in its text and the tokens of each
+// interpolation, which keep their position in the file
+fn string_tokens(
+    file: &Arc<SourceFile>,
+    chars: &[(usize, char)],
+    from: usize,
+    len: usize,
+    line: usize,
+    col: usize,
+) -> Result<Vec<LexerToken>, EgoError> {
+    let span = token_span(file, chars, from, len, line, col);
+    let closing = from + len - 1;
+    let (mut line, mut col) = (line, col + 1);
+    let mut current = from + 1;
+
+    let mut parts = vec![];
+    let mut text = String::new();
+    let mut text_start = (current, line, col);
+    while current < closing {
+        let c = chars[current].1;
+        match c {
+            '\\' => {
+                let (unescaped, escape_len) = unescape(&chars[current..closing]);
+                match unescaped {
+                    Some(c) => text.push(c),
+                    None => {
+                        let sequence: String = chars[current..current + escape_len]
+                            .iter()
+                            .map(|(_, c)| c)
+                            .collect();
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!("Invalid escape sequence '{sequence}' in string literal")
+                                .as_str(),
+                            Some(token_span(file, chars, current, escape_len, line, col)),
                         ));
-                        current_token = String::new();
-                        current_token.push(c);
                     }
                 }
-                // whitespace types
-                ' ' | '\n' | '\t' => {
-                    if keywords.contains(&current_token.as_str()) {
-                        tokens.push(token_with_type(
-                            current_token,
-                            line_counter,
-                            line_char_counter,
-                        ));
-                        current_token = String::new();
-                    } else if current_token.len() > 0 {
-                        // if not empty
-                        tokens.push(token_with_type(
-                            current_token,
-                            line_counter,
-                            line_char_counter,
-                        ));
-                        current_token = String::new();
-                    }
-
-                    if c == '\n' {
-                        line_counter += 1;
-                        line_char_counter = 0;
-                    }
+                col += escape_len;
+                current += escape_len;
+            }
+            '{' => {
+                let (start, start_line, start_col) = text_start;
+                let span = token_span(file, chars, start, current - start, start_line, start_col);
+                if !text.is_empty() {
+                    let text = std::mem::take(&mut text);
+                    parts.push(LexerToken::new(LexerTokenType::StringLiteral, text, span));
                 }
-                // accumulating numbers
-                _ if c.is_numeric() && current_token.chars().all(|char| char.is_numeric()) => {
-                    if chars
-                        .peek()
-                        .is_some_and(|char| char.is_numeric() || char == &'.')
-                    {
-                        current_token.push(c);
-                    } else {
-                        current_token.push(c);
-                        tokens.push(token_with_type(
-                            current_token,
-                            line_counter,
-                            line_char_counter,
+
+                let open_span = token_span(file, chars, current, 1, line, col);
+                let close = match interpolation_end(&chars[..closing], current) {
+                    Some(close) => close,
+                    None => {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            "Expected '}' to close the interpolation",
+                            Some(open_span),
                         ));
-                        current_token = String::new();
                     }
+                };
+                let tokens = lex_chars(file, &chars[..close], current + 1, line, col + 1)?;
+                if tokens.is_empty() {
+                    return Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        "Expected an expression inside the interpolation",
+                        Some(token_span(
+                            file,
+                            chars,
+                            current,
+                            close - current + 1,
+                            line,
+                            col,
+                        )),
+                    ));
                 }
-                // characters that are not whitespace
-                _ if !c.is_whitespace() => {
-                    // check for numeric strings
-                    current_token.push(c);
+
+                for (_, c) in &chars[current..close] {
+                    advance(*c, &mut line, &mut col);
                 }
-                _ => {}
+                let close_span = token_span(file, chars, close, 1, line, col);
+                col += 1;
+                current = close + 1;
+                text_start = (current, line, col);
+
+                parts.push(LexerToken::new(
+                    LexerTokenType::InterpolationStart,
+                    "{".to_string(),
+                    open_span,
+                ));
+                parts.extend(tokens);
+                parts.push(LexerToken::new(
+                    LexerTokenType::InterpolationEnd,
+                    "}".to_string(),
+                    close_span,
+                ));
+            }
+            _ => {
+                text.push(c);
+                advance(c, &mut line, &mut col);
+                current += 1;
             }
         }
+    }
 
-        // last character in the source code
-        if char_counter == source.len() && current_token.len() > 0 {
-            tokens.push(token_with_type(
-                current_token,
-                line_counter,
-                line_char_counter,
-            ));
-            current_token = String::new()
-        }
+    if parts.is_empty() {
+        return Ok(vec![LexerToken::new(
+            LexerTokenType::StringLiteral,
+            text,
+            span,
+        )]);
+    }
 
-        // keep track of current char index
-        line_char_counter += 1;
-        char_counter += 1;
+    if !text.is_empty() {
+        let (start, start_line, start_col) = text_start;
+        let text_span = token_span(file, chars, start, closing - start, start_line, start_col);
+        parts.push(LexerToken::new(
+            LexerTokenType::StringLiteral,
+            text,
+            text_span,
+        ));
     }
+    let closing_span = token_span(file, chars, closing, 1, line, col);
 
-    return tokens;
+    let mut tokens = vec![LexerToken::new(
+        LexerTokenType::TemplateStart,
+        "\"".to_string(),
+        span,
+    )];
+    tokens.extend(parts);
+    tokens.push(LexerToken::new(
+        LexerTokenType::TemplateEnd,
+        "\"".to_string(),
+        closing_span,
+    ));
+    Ok(tokens)
 }
 
-fn token_with_type(token: String, line: usize, at: usize) -> LexerToken {
-    match token.as_str() {
-        "import" => LexerToken::new(LexerTokenType::ImportKeyword, token, line, at),
-        "fn" => LexerToken::new(LexerTokenType::FnKeyword, token, line, at),
-        "while" => LexerToken::new(LexerTokenType::WhileKeyword, token, line, at),
-        "let" => LexerToken::new(LexerTokenType::LetKeyword, token, line, at),
-        "if" => LexerToken::new(LexerTokenType::IfKeyword, token, line, at),
-        "else" => LexerToken::new(LexerTokenType::ElseKeyword, token, line, at),
-        "true" => LexerToken::new(LexerTokenType::TrueKeyword, token, line, at),
-        "false" => LexerToken::new(LexerTokenType::FalseKeyword, token, line, at),
-        "return" => LexerToken::new(LexerTokenType::ReturnKeyword, token, line, at),
-        "break" => LexerToken::new(LexerTokenType::BreakKeyword, token, line, at),
-        "nothing" => LexerToken::new(LexerTokenType::NothingKeyword, token, line, at),
-        "string" => LexerToken::new(LexerTokenType::StringKeyword, token, line, at),
-        "number" => LexerToken::new(LexerTokenType::NumberKeyword, token, line, at),
-        "bool" => LexerToken::new(LexerTokenType::BoolKeyword, token, line, at),
-        "(" => LexerToken::new(LexerTokenType::OpenParenthesis, token, line, at),
-        ")" => LexerToken::new(LexerTokenType::CloseParenthesis, token, line, at),
-        "{" => LexerToken::new(LexerTokenType::OpenCurlyBrace, token, line, at),
-        "}" => LexerToken::new(LexerTokenType::CloseCurlyBrace, token, line, at),
-        "[" => LexerToken::new(LexerTokenType::OpenSquareBracket, token, line, at),
-        "]" => LexerToken::new(LexerTokenType::CloseSquareBracket, token, line, at),
-        "." => LexerToken::new(LexerTokenType::Dot, token, line, at),
-        ":" => LexerToken::new(LexerTokenType::Colon, token, line, at),
-        "," => LexerToken::new(LexerTokenType::Comma, token, line, at),
-        ";" => LexerToken::new(LexerTokenType::EndOfStatement, token, line, at),
-        "!" => LexerToken::new(LexerTokenType::NotOperator, token, line, at),
-        "!=" => LexerToken::new(LexerTokenType::NotEqualOperator, token, line, at),
-        "=" => LexerToken::new(LexerTokenType::AssignmentOperator, token, line, at),
-        "==" => LexerToken::new(LexerTokenType::EqualityOperator, token, line, at),
-        "+" => LexerToken::new(LexerTokenType::AddOperator, token, line, at),
-        "-" => LexerToken::new(LexerTokenType::SubtractOperator, token, line, at),
-        "*" => LexerToken::new(LexerTokenType::MultiplyOperator, token, line, at),
-        "|" => LexerToken::new(LexerTokenType::OrOperator, token, line, at),
-        "&" => LexerToken::new(LexerTokenType::AmpersandOperator, token, line, at),
-        "/" => LexerToken::new(LexerTokenType::DivideOperator, token, line, at),
-        ">" => LexerToken::new(LexerTokenType::GreaterThanOperator, token, line, at),
-        ">=" => LexerToken::new(LexerTokenType::GreaterThanOrEqualOperator, token, line, at),
-        "<" => LexerToken::new(LexerTokenType::LessThanOperator, token, line, at),
-        "<=" => LexerToken::new(LexerTokenType::LessThanOrEqualOperator, token, line, at),
-        _ if token.chars().next() == Some('"') && token.chars().last() == Some('"') => {
-            LexerToken::new(LexerTokenType::StringLiteral, token, line, at)
+// \n | \t | \r | \0 | \\ | \" | \{ | \} | \u{1F600}, returns the
+// escaped char and the number of chars of the sequence
+fn unescape(chars: &[(usize, char)]) -> (Option<char>, usize) {
+    let escaped = match chars.get(1).map(|(_, c)| *c) {
+        Some('n') => '\n',
+        Some('t') => '\t',
+        Some('r') => '\r',
+        Some('0') => '\0',
+        Some(c @ ('\\' | '"' | '{' | '}')) => c,
+        Some('u') if chars.get(2).is_some_and(|(_, c)| *c == '{') => {
+            // \u{1F600}
+            let digits: String = chars[3..]
+                .iter()
+                .map(|(_, c)| *c)
+                .take_while(|c| *c != '}')
+                .collect();
+            let escape_len = 3 + digits.chars().count() + 1;
+            if escape_len > chars.len() {
+                return (None, chars.len());
+            }
+            let unicode = u32::from_str_radix(&digits, 16)
+                .ok()
+                .and_then(char::from_u32);
+            return (unicode, escape_len);
         }
-        _ if is_number(&token) => LexerToken::new(LexerTokenType::Number, token, line, at),
-        _ if is_identifier(&token) => LexerToken::new(LexerTokenType::Identifier, token, line, at),
-        _ => LexerToken::new(LexerTokenType::Unknown, token, line, at),
-    }
+        _ => return (None, 2.min(chars.len())),
+    };
+    (Some(escaped), 2)
 }
 
 fn is_number(token: &String) -> bool {
@@ -1,29 +1,43 @@
 use std::fmt;
 
+use crate::core::span::Span;
+
 #[derive(Clone, Debug)]
 pub enum LexerTokenType {
     LetKeyword,
+    ConstKeyword,
     ImportKeyword,
     FnKeyword,
+    StructKeyword,
+    ImplKeyword,
+    EnumKeyword,
+    MatchKeyword,
     WhileKeyword,
+    ForKeyword,
+    InKeyword,
     IfKeyword,
     ElseKeyword,
     TrueKeyword,
     FalseKeyword,
     ReturnKeyword,
     BreakKeyword,
+    ContinueKeyword,
     NothingKeyword,
     StringKeyword,
     NumberKeyword,
     BoolKeyword,
     Identifier,
     AssignmentOperator,
+    FatArrow,
     AddOperator,
     SubtractOperator,
     MultiplyOperator,
     DivideOperator,
+    IntDivideOperator,
+    ModuloOperator,
+    PowerOperator,
     OrOperator,
-    AmpersandOperator,
+    AndOperator,
     LessThanOperator,
     LessThanOrEqualOperator,
     GreaterThanOperator,
@@ -31,7 +45,12 @@ pub enum LexerTokenType {
     EqualityOperator,
     NotEqualOperator,
     NotOperator,
+    RangeOperator,
     StringLiteral,
+    TemplateStart,
+    TemplateEnd,
+    InterpolationStart,
+    InterpolationEnd,
     Number,
     OpenParenthesis,
     CloseParenthesis,
@@ -50,35 +69,52 @@ impl fmt::Display for LexerTokenType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             LexerTokenType::LetKeyword => write!(f, "LetKeyword"),
+            LexerTokenType::ConstKeyword => write!(f, "ConstKeyword"),
             LexerTokenType::ImportKeyword => write!(f, "ImportKeyword"),
             LexerTokenType::FnKeyword => write!(f, "FnKeyword"),
+            LexerTokenType::StructKeyword => write!(f, "StructKeyword"),
+            LexerTokenType::ImplKeyword => write!(f, "ImplKeyword"),
+            LexerTokenType::EnumKeyword => write!(f, "EnumKeyword"),
+            LexerTokenType::MatchKeyword => write!(f, "MatchKeyword"),
             LexerTokenType::WhileKeyword => write!(f, "WhileKeyword"),
+            LexerTokenType::ForKeyword => write!(f, "ForKeyword"),
+            LexerTokenType::InKeyword => write!(f, "InKeyword"),
             LexerTokenType::IfKeyword => write!(f, "IfKeyword"),
             LexerTokenType::ElseKeyword => write!(f, "ElseKeyword"),
             LexerTokenType::TrueKeyword => write!(f, "TrueKeyword"),
             LexerTokenType::FalseKeyword => write!(f, "FalseKeyword"),
             LexerTokenType::ReturnKeyword => write!(f, "ReturnKeyword"),
             LexerTokenType::BreakKeyword => write!(f, "BreakKeyword"),
+            LexerTokenType::ContinueKeyword => write!(f, "ContinueKeyword"),
             LexerTokenType::NothingKeyword => write!(f, "NothingKeyword"),
             LexerTokenType::StringKeyword => write!(f, "StringKeyword"),
             LexerTokenType::NumberKeyword => write!(f, "NumberKeyword"),
             LexerTokenType::BoolKeyword => write!(f, "BoolKeyword"),
             LexerTokenType::Identifier => write!(f, "Identifier"),
             LexerTokenType::AssignmentOperator => write!(f, "AssignmentOperator"),
+            LexerTokenType::FatArrow => write!(f, "FatArrow"),
             LexerTokenType::EqualityOperator => write!(f, "EqualityOperator"),
             LexerTokenType::AddOperator => write!(f, "AddOperator"),
             LexerTokenType::SubtractOperator => write!(f, "SubtractOperator"),
             LexerTokenType::MultiplyOperator => write!(f, "MultiplyOperator"),
             LexerTokenType::DivideOperator => write!(f, "DivideOperator"),
+            LexerTokenType::IntDivideOperator => write!(f, "IntDivideOperator"),
+            LexerTokenType::ModuloOperator => write!(f, "ModuloOperator"),
+            LexerTokenType::PowerOperator => write!(f, "PowerOperator"),
             LexerTokenType::OrOperator => write!(f, "OrOperator"),
-            LexerTokenType::AmpersandOperator => write!(f, "AmpersandOperator"),
+            LexerTokenType::AndOperator => write!(f, "AndOperator"),
             LexerTokenType::LessThanOperator => write!(f, "LessThanOperator"),
             LexerTokenType::LessThanOrEqualOperator => write!(f, "LessThanOrEqualOperator"),
             LexerTokenType::GreaterThanOperator => write!(f, "GreaterThanOperator"),
             LexerTokenType::GreaterThanOrEqualOperator => write!(f, "GreaterThanOrEqualOperator"),
             LexerTokenType::NotEqualOperator => write!(f, "NotEqualOperator"),
             LexerTokenType::NotOperator => write!(f, "NotOperator"),
+            LexerTokenType::RangeOperator => write!(f, "RangeOperator"),
             LexerTokenType::StringLiteral => write!(f, "StringLiteral"),
+            LexerTokenType::TemplateStart => write!(f, "TemplateStart"),
+            LexerTokenType::TemplateEnd => write!(f, "TemplateEnd"),
+            LexerTokenType::InterpolationStart => write!(f, "InterpolationStart"),
+            LexerTokenType::InterpolationEnd => write!(f, "InterpolationEnd"),
             LexerTokenType::Number => write!(f, "Number"),
             LexerTokenType::OpenParenthesis => write!(f, "OpenParenthesis"),
             LexerTokenType::CloseParenthesis => write!(f, "CloseParenthesis"),
@@ -105,17 +141,15 @@ impl PartialEq for LexerTokenType {
 pub struct LexerToken {
     pub token_type: LexerTokenType,
     pub value: String,
-    pub line: usize,
-    pub at: usize,
+    pub span: Span,
 }
 
 impl LexerToken {
-    pub fn new(token_type: LexerTokenType, value: String, line: usize, at: usize) -> LexerToken {
+    pub fn new(token_type: LexerTokenType, value: String, span: Span) -> LexerToken {
         LexerToken {
             token_type,
             value,
-            line,
-            at,
+            span,
         }
     }
 }
@@ -124,8 +158,8 @@ impl fmt::Display for LexerToken {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}: {} (line: {}, char: {})",
-            self.token_type, self.value, self.line, self.at
+            "{}: {} (line: {}, col: {})",
+            self.token_type, self.value, self.span.line, self.span.col
         )
     }
 }
@@ -1,3 +1,5 @@
+use crate::core::span::Span;
+
 use super::{identifier::Identifier, Expression};
 
 #[derive(Debug, Clone)]
@@ -6,8 +8,7 @@ pub struct AssignamentNode {
     pub identifier: Identifier,
     pub init: Expression,
     pub var_type: VarType,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl AssignamentNode {
@@ -15,15 +16,13 @@ impl AssignamentNode {
         identifier: Identifier,
         init: Expression,
         var_type: VarType,
-        at: usize,
-        line: usize,
+        span: Span,
     ) -> AssignamentNode {
         AssignamentNode {
             identifier,
             init,
             var_type,
-            at,
-            line,
+            span,
         }
     }
 }
@@ -1,3 +1,5 @@
+use crate::core::span::Span;
+
 use super::Expression;
 
 #[derive(Debug, Clone)]
@@ -5,8 +7,7 @@ pub struct BinaryExpression {
     pub operator: String,
     pub left: Box<Expression>,
     pub right: Box<Expression>,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl BinaryExpression {
@@ -14,15 +15,13 @@ impl BinaryExpression {
         operator: String,
         left: Box<Expression>,
         right: Box<Expression>,
-        at: usize,
-        line: usize,
+        span: Span,
     ) -> BinaryExpression {
         BinaryExpression {
             operator,
             left,
             right,
-            at,
-            line,
+            span,
         }
     }
 }
@@ -1,13 +1,19 @@
+use crate::core::span::Span;
+
 use super::AstNodeType;
 
 #[derive(Debug, Clone)]
 pub struct Block {
     pub children: Vec<AstNodeType>,
+    pub span: Span,
 }
 
 impl Block {
-    pub fn new() -> Block {
-        Block { children: vec![] }
+    pub fn new(span: Span) -> Block {
+        Block {
+            children: vec![],
+            span,
+        }
     }
     pub fn add_child(&mut self, node: AstNodeType) {
         self.children.push(node);
@@ -1,12 +1,13 @@
+use crate::core::span::Span;
+
 #[derive(Debug, Clone)]
 pub struct Bool {
     pub value: bool,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl Bool {
-    pub fn new(value: bool, at: usize, line: usize) -> Bool {
-        Bool { value, at, line }
+    pub fn new(value: bool, span: Span) -> Bool {
+        Bool { value, span }
     }
 }
@@ -1,11 +1,15 @@
+use crate::core::span::Span;
+
+use super::identifier::Identifier;
+
 #[derive(Debug, Clone)]
 pub struct BreakStatement {
-    pub at: usize,
-    pub line: usize,
+    pub label: Option<Identifier>,
+    pub span: Span,
 }
 
 impl BreakStatement {
-    pub fn new(at: usize, line: usize) -> BreakStatement {
-        BreakStatement { at, line }
+    pub fn new(label: Option<Identifier>, span: Span) -> BreakStatement {
+        BreakStatement { label, span }
     }
 }
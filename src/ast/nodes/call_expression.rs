@@ -1,21 +1,29 @@
-use super::{group::Group, identifier::Identifier};
+use crate::core::span::Span;
+
+use super::{group::Group, Expression};
 
 #[derive(Debug, Clone)]
 pub struct CallExpression {
     //pub type: String,
-    pub identifier: Identifier,
+    pub callee: Box<Expression>,
     pub arguments: Group,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl CallExpression {
-    pub fn new(identifier: Identifier, arguments: Group, at: usize, line: usize) -> CallExpression {
+    pub fn new(callee: Box<Expression>, arguments: Group, span: Span) -> CallExpression {
         CallExpression {
-            identifier,
+            callee,
             arguments,
-            at,
-            line,
+            span,
+        }
+    }
+
+    // name of the called function when it is called by its identifier
+    pub fn callee_name(&self) -> Option<&str> {
+        match self.callee.as_ref() {
+            Expression::Identifier(identifier) => Some(identifier.name.as_str()),
+            _ => None,
         }
     }
 }
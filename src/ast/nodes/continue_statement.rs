@@ -0,0 +1,15 @@
+use crate::core::span::Span;
+
+use super::identifier::Identifier;
+
+#[derive(Debug, Clone)]
+pub struct ContinueStatement {
+    pub label: Option<Identifier>,
+    pub span: Span,
+}
+
+impl ContinueStatement {
+    pub fn new(label: Option<Identifier>, span: Span) -> ContinueStatement {
+        ContinueStatement { label, span }
+    }
+}
@@ -1,14 +1,22 @@
-use super::block::Block;
+use crate::core::span::Span;
+
+use super::{block::Block, if_statement::IfStatement};
+
+#[derive(Debug, Clone)]
+pub enum ElseBody {
+    Block(Block),
+    // else if ... {...}
+    If(Box<IfStatement>),
+}
 
 #[derive(Debug, Clone)]
 pub struct ElseStatement {
-    pub body: Block,
-    pub at: usize,
-    pub line: usize,
+    pub body: ElseBody,
+    pub span: Span,
 }
 
 impl ElseStatement {
-    pub fn new(body: Block, at: usize, line: usize) -> ElseStatement {
-        ElseStatement { body, at, line }
+    pub fn new(body: ElseBody, span: Span) -> ElseStatement {
+        ElseStatement { body, span }
     }
 }
@@ -0,0 +1,34 @@
+use crate::core::span::Span;
+
+use super::identifier::Identifier;
+
+#[derive(Debug, Clone)]
+pub struct EnumVariant {
+    pub identifier: Identifier,
+    // payload fields keep their optional type annotation,
+    // there are none for a variant like Idle
+    pub fields: Vec<Identifier>,
+}
+
+impl EnumVariant {
+    pub fn new(identifier: Identifier, fields: Vec<Identifier>) -> EnumVariant {
+        EnumVariant { identifier, fields }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumDeclaration {
+    pub identifier: Identifier,
+    pub variants: Vec<EnumVariant>,
+    pub span: Span,
+}
+
+impl EnumDeclaration {
+    pub fn new(identifier: Identifier, variants: Vec<EnumVariant>, span: Span) -> EnumDeclaration {
+        EnumDeclaration {
+            identifier,
+            variants,
+            span,
+        }
+    }
+}
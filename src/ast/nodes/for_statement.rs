@@ -0,0 +1,24 @@
+use crate::core::span::Span;
+
+use super::{block::Block, identifier::Identifier, Expression};
+
+#[derive(Debug, Clone)]
+pub struct ForStatement {
+    pub item: Identifier,
+    pub iterable: Expression,
+    pub body: Block,
+    pub label: Option<Identifier>,
+    pub span: Span,
+}
+
+impl ForStatement {
+    pub fn new(item: Identifier, iterable: Expression, body: Block, span: Span) -> ForStatement {
+        ForStatement {
+            item,
+            iterable,
+            body,
+            label: None,
+            span,
+        }
+    }
+}
@@ -1,29 +1,31 @@
-use super::{block::Block, identifier::Identifier};
+use crate::core::span::Span;
+
+use super::{block::Block, identifier::Identifier, Type};
 
 #[derive(Debug, Clone)]
 pub struct FunctionDeclaration {
     //pub type: String,
     pub identifier: Identifier,
     pub parameters: Vec<Identifier>,
+    pub return_type: Option<Type>,
     pub body: Block,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl FunctionDeclaration {
     pub fn new(
         identifier: Identifier,
         parameters: Vec<Identifier>,
+        return_type: Option<Type>,
         body: Block,
-        at: usize,
-        line: usize,
+        span: Span,
     ) -> FunctionDeclaration {
         FunctionDeclaration {
             identifier,
             parameters,
+            return_type,
             body,
-            at,
-            line,
+            span,
         }
     }
 }
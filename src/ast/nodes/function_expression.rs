@@ -0,0 +1,27 @@
+use crate::core::span::Span;
+
+use super::{block::Block, identifier::Identifier, Type};
+
+#[derive(Debug, Clone)]
+pub struct FunctionExpression {
+    pub parameters: Vec<Identifier>,
+    pub return_type: Option<Type>,
+    pub body: Block,
+    pub span: Span,
+}
+
+impl FunctionExpression {
+    pub fn new(
+        parameters: Vec<Identifier>,
+        return_type: Option<Type>,
+        body: Block,
+        span: Span,
+    ) -> FunctionExpression {
+        FunctionExpression {
+            parameters,
+            return_type,
+            body,
+            span,
+        }
+    }
+}
@@ -1,18 +1,18 @@
+use crate::core::span::Span;
+
 use super::Expression;
 
 #[derive(Debug, Clone)]
 pub struct Group {
     pub children: Vec<Option<Expression>>,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl Group {
-    pub fn new(at: usize, line: usize) -> Group {
+    pub fn new(span: Span) -> Group {
         Group {
             children: vec![],
-            at,
-            line,
+            span,
         }
     }
     pub fn add_child(&mut self, node: Option<Expression>) {
@@ -1,19 +1,19 @@
+use crate::core::span::Span;
+
 use super::Type;
 
 #[derive(Debug, Clone)]
 pub struct Identifier {
     pub name: String,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
     pub annotation: Option<Type>,
 }
 
 impl Identifier {
-    pub fn new(name: String, at: usize, line: usize) -> Identifier {
+    pub fn new(name: String, span: Span) -> Identifier {
         Identifier {
             name,
-            line,
-            at,
+            span,
             annotation: None,
         }
     }
@@ -1,3 +1,5 @@
+use crate::core::span::Span;
+
 use super::{block::Block, else_statement::ElseStatement, Expression};
 
 #[derive(Debug, Clone)]
@@ -5,8 +7,7 @@ pub struct IfStatement {
     pub condition: Expression,
     pub body: Block,
     pub else_node: Option<ElseStatement>,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl IfStatement {
@@ -14,15 +15,13 @@ impl IfStatement {
         condition: Expression,
         body: Block,
         else_node: Option<ElseStatement>,
-        at: usize,
-        line: usize,
+        span: Span,
     ) -> IfStatement {
         IfStatement {
             condition,
             body,
             else_node,
-            at,
-            line,
+            span,
         }
     }
 }
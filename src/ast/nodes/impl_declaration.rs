@@ -0,0 +1,24 @@
+use crate::core::span::Span;
+
+use super::{function_declaration::FunctionDeclaration, identifier::Identifier};
+
+#[derive(Debug, Clone)]
+pub struct ImplDeclaration {
+    pub identifier: Identifier,
+    pub methods: Vec<FunctionDeclaration>,
+    pub span: Span,
+}
+
+impl ImplDeclaration {
+    pub fn new(
+        identifier: Identifier,
+        methods: Vec<FunctionDeclaration>,
+        span: Span,
+    ) -> ImplDeclaration {
+        ImplDeclaration {
+            identifier,
+            methods,
+            span,
+        }
+    }
+}
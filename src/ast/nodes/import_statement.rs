@@ -1,23 +1,18 @@
+use crate::core::span::Span;
+
 #[derive(Debug, Clone)]
 pub struct ImportStatement {
     pub module: Vec<String>,
     pub members: Vec<String>,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl ImportStatement {
-    pub fn new(
-        module: Vec<String>,
-        members: Vec<String>,
-        at: usize,
-        line: usize,
-    ) -> ImportStatement {
+    pub fn new(module: Vec<String>, members: Vec<String>, span: Span) -> ImportStatement {
         ImportStatement {
             module,
             members,
-            at,
-            line,
+            span,
         }
     }
 }
@@ -0,0 +1,20 @@
+use crate::core::span::Span;
+
+use super::Expression;
+
+#[derive(Debug, Clone)]
+pub struct IndexExpression {
+    pub object: Box<Expression>,
+    pub index: Box<Expression>,
+    pub span: Span,
+}
+
+impl IndexExpression {
+    pub fn new(object: Box<Expression>, index: Box<Expression>, span: Span) -> IndexExpression {
+        IndexExpression {
+            object,
+            index,
+            span,
+        }
+    }
+}
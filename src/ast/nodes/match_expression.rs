@@ -0,0 +1,81 @@
+use crate::core::span::Span;
+
+use super::{block::Block, identifier::Identifier, Expression};
+
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    // 1 | "idle" | true | nothing
+    Literal(Expression),
+    // State.Running(speed)
+    Variant(VariantPattern),
+    // _
+    Wildcard(Span),
+    // any value, bound to the name inside the arm
+    Binding(Identifier),
+}
+
+impl Pattern {
+    pub fn span(&self) -> &Span {
+        match self {
+            Pattern::Literal(v) => v.span(),
+            Pattern::Variant(v) => &v.span,
+            Pattern::Wildcard(v) => v,
+            Pattern::Binding(v) => &v.span,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VariantPattern {
+    pub enumeration: Identifier,
+    pub variant: Identifier,
+    // one pattern for each payload field
+    pub patterns: Vec<Pattern>,
+    pub span: Span,
+}
+
+impl VariantPattern {
+    pub fn new(
+        enumeration: Identifier,
+        variant: Identifier,
+        patterns: Vec<Pattern>,
+        span: Span,
+    ) -> VariantPattern {
+        VariantPattern {
+            enumeration,
+            variant,
+            patterns,
+            span,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    // arms written as an expression are a block with a single child
+    pub body: Block,
+}
+
+impl MatchArm {
+    pub fn new(pattern: Pattern, body: Block) -> MatchArm {
+        MatchArm { pattern, body }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchExpression {
+    pub subject: Expression,
+    pub arms: Vec<MatchArm>,
+    pub span: Span,
+}
+
+impl MatchExpression {
+    pub fn new(subject: Expression, arms: Vec<MatchArm>, span: Span) -> MatchExpression {
+        MatchExpression {
+            subject,
+            arms,
+            span,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::core::span::Span;
+
+use super::Expression;
+
+#[derive(Debug, Clone)]
+pub struct MemberAssignament {
+    pub target: Expression,
+    pub init: Expression,
+    pub span: Span,
+}
+
+impl MemberAssignament {
+    pub fn new(target: Expression, init: Expression, span: Span) -> MemberAssignament {
+        MemberAssignament { target, init, span }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::core::span::Span;
+
+use super::{identifier::Identifier, Expression};
+
+#[derive(Debug, Clone)]
+pub struct MemberExpression {
+    pub object: Box<Expression>,
+    pub property: Identifier,
+    pub span: Span,
+}
+
+impl MemberExpression {
+    pub fn new(object: Box<Expression>, property: Identifier, span: Span) -> MemberExpression {
+        MemberExpression {
+            object,
+            property,
+            span,
+        }
+    }
+}
@@ -4,78 +4,98 @@ pub mod block;
 pub mod bool;
 pub mod break_statement;
 pub mod call_expression;
+pub mod continue_statement;
 pub mod else_statement;
+pub mod enum_declaration;
+pub mod for_statement;
 pub mod function_declaration;
+pub mod function_expression;
 pub mod group;
 pub mod identifier;
 pub mod if_statement;
+pub mod impl_declaration;
 pub mod import_statement;
+pub mod index_expression;
+pub mod match_expression;
+pub mod member_assignament;
+pub mod member_expression;
 pub mod module;
 pub mod nothing;
 pub mod number;
+pub mod object;
+pub mod range_expression;
 pub mod return_statement;
 pub mod string_literal;
+pub mod struct_declaration;
+pub mod struct_expression;
+pub mod template_string;
+pub mod unary_expression;
 pub mod vector;
 pub mod while_statement;
 use std::fmt;
 
+use crate::core::span::Span;
+
 use self::{
     assignament_statement::AssignamentNode, binary_expression::BinaryExpression, block::Block,
     bool::Bool, break_statement::BreakStatement, call_expression::CallExpression,
-    else_statement::ElseStatement, function_declaration::FunctionDeclaration, group::Group,
-    identifier::Identifier, if_statement::IfStatement, import_statement::ImportStatement,
-    nothing::Nothing, number::Number, return_statement::ReturnStatement,
-    string_literal::StringLiteral, vector::Vector, while_statement::WhileStatement,
+    continue_statement::ContinueStatement, else_statement::ElseStatement,
+    enum_declaration::EnumDeclaration, for_statement::ForStatement,
+    function_declaration::FunctionDeclaration, function_expression::FunctionExpression,
+    group::Group, identifier::Identifier, if_statement::IfStatement,
+    impl_declaration::ImplDeclaration, import_statement::ImportStatement,
+    index_expression::IndexExpression, match_expression::MatchExpression,
+    member_assignament::MemberAssignament, member_expression::MemberExpression, nothing::Nothing,
+    number::Number, object::Object, range_expression::RangeExpression,
+    return_statement::ReturnStatement, string_literal::StringLiteral,
+    struct_declaration::StructDeclaration, struct_expression::StructExpression,
+    template_string::TemplateString, unary_expression::UnaryExpression, vector::Vector,
+    while_statement::WhileStatement,
 };
 
 #[derive(Debug, Clone)]
 pub enum AstNodeType {
     IfStatement(IfStatement),
     WhileStatement(WhileStatement),
+    ForStatement(ForStatement),
     ImportStatement(ImportStatement),
     ReturnStatement(ReturnStatement),
     BreakStatement(BreakStatement),
+    ContinueStatement(ContinueStatement),
     ElseStatement(ElseStatement),
     Group(Group),
     Vector(Vector),
     Block(Block),
     Expression(Expression),
     AssignamentStatement(AssignamentNode),
+    MemberAssignament(MemberAssignament),
     FunctionDeclaration(FunctionDeclaration),
+    StructDeclaration(StructDeclaration),
+    ImplDeclaration(ImplDeclaration),
+    EnumDeclaration(EnumDeclaration),
 }
 
 impl AstNodeType {
-    pub fn at(&self) -> usize {
-        match self {
-            AstNodeType::IfStatement(v) => v.at,
-            AstNodeType::WhileStatement(v) => v.at,
-            AstNodeType::ImportStatement(v) => v.at,
-            AstNodeType::ReturnStatement(v) => v.at,
-            AstNodeType::BreakStatement(v) => v.at,
-            AstNodeType::ElseStatement(v) => v.at,
-            AstNodeType::Group(v) => v.at,
-            AstNodeType::Vector(v) => v.at,
-            AstNodeType::Block(_v) => 0,
-            AstNodeType::Expression(_v) => 0,
-            AstNodeType::AssignamentStatement(v) => v.at,
-            AstNodeType::FunctionDeclaration(v) => v.at,
-        }
-    }
-
-    pub fn line(&self) -> usize {
+    pub fn span(&self) -> &Span {
         match self {
-            AstNodeType::IfStatement(v) => v.line,
-            AstNodeType::WhileStatement(v) => v.line,
-            AstNodeType::ImportStatement(v) => v.line,
-            AstNodeType::ReturnStatement(v) => v.line,
-            AstNodeType::BreakStatement(v) => v.line,
-            AstNodeType::ElseStatement(v) => v.line,
-            AstNodeType::Group(v) => v.line,
-            AstNodeType::Vector(v) => v.line,
-            AstNodeType::Block(_v) => 0,
-            AstNodeType::Expression(_v) => 0,
-            AstNodeType::AssignamentStatement(v) => v.line,
-            AstNodeType::FunctionDeclaration(v) => v.line,
+            AstNodeType::IfStatement(v) => &v.span,
+            AstNodeType::WhileStatement(v) => &v.span,
+            AstNodeType::ForStatement(v) => &v.span,
+            AstNodeType::ImportStatement(v) => &v.span,
+            AstNodeType::ReturnStatement(v) => &v.span,
+            AstNodeType::BreakStatement(v) => &v.span,
+            AstNodeType::ContinueStatement(v) => &v.span,
+            AstNodeType::ElseStatement(v) => &v.span,
+            AstNodeType::Group(v) => &v.span,
+            AstNodeType::Vector(v) => &v.span,
+            AstNodeType::Block(v) => &v.span,
+            AstNodeType::Expression(v) => v.span(),
+            AstNodeType::AssignamentStatement(v) => &v.span,
+            AstNodeType::MemberAssignament(v) => &v.span,
+            AstNodeType::FunctionDeclaration(v) => &v.span,
+            AstNodeType::StructDeclaration(v) => &v.span,
+            AstNodeType::ImplDeclaration(v) => &v.span,
+            AstNodeType::EnumDeclaration(v) => &v.span,
         }
     }
 }
@@ -87,14 +107,21 @@ impl fmt::Display for AstNodeType {
             AstNodeType::ElseStatement(_) => write!(f, "ElseStatement"),
             AstNodeType::ImportStatement(_) => write!(f, "ImportStatement"),
             AstNodeType::WhileStatement(_) => write!(f, "WhileStatement"),
+            AstNodeType::ForStatement(_) => write!(f, "ForStatement"),
             AstNodeType::ReturnStatement(_) => write!(f, "ReturnStatement"),
             AstNodeType::BreakStatement(_) => write!(f, "BreakStatement"),
+            AstNodeType::ContinueStatement(_) => write!(f, "ContinueStatement"),
             AstNodeType::Block(_) => write!(f, "Block"),
             AstNodeType::Group(_) => write!(f, "Group"),
             AstNodeType::Vector(_) => write!(f, "Vector"),
             AstNodeType::FunctionDeclaration(_) => write!(f, "FunctionDeclaration"),
+            AstNodeType::StructDeclaration(_) => write!(f, "StructDeclaration"),
+            AstNodeType::ImplDeclaration(_) => write!(f, "ImplDeclaration"),
+            AstNodeType::EnumDeclaration(_) => write!(f, "EnumDeclaration"),
             AstNodeType::AssignamentStatement(_) => write!(f, "AssignamentStatement"),
+            AstNodeType::MemberAssignament(_) => write!(f, "MemberAssignament"),
             AstNodeType::Expression(Expression::StringLiteral(_)) => write!(f, "StringLiteral"),
+            AstNodeType::Expression(Expression::TemplateString(_)) => write!(f, "TemplateString"),
             AstNodeType::Expression(Expression::Number(_)) => write!(f, "Number"),
             AstNodeType::Expression(Expression::Bool(_)) => write!(f, "Number"),
             AstNodeType::Expression(Expression::Identifier(_)) => write!(f, "Identifier"),
@@ -105,6 +132,30 @@ impl fmt::Display for AstNodeType {
             AstNodeType::Expression(Expression::BinaryExpression(_)) => {
                 write!(f, "BinaryExpression")
             }
+            AstNodeType::Expression(Expression::UnaryExpression(_)) => {
+                write!(f, "UnaryExpression")
+            }
+            AstNodeType::Expression(Expression::Vector(_)) => write!(f, "Vector"),
+            AstNodeType::Expression(Expression::IndexExpression(_)) => {
+                write!(f, "IndexExpression")
+            }
+            AstNodeType::Expression(Expression::MemberExpression(_)) => {
+                write!(f, "MemberExpression")
+            }
+            AstNodeType::Expression(Expression::Object(_)) => write!(f, "Object"),
+            AstNodeType::Expression(Expression::StructExpression(_)) => {
+                write!(f, "StructExpression")
+            }
+            AstNodeType::Expression(Expression::RangeExpression(_)) => {
+                write!(f, "RangeExpression")
+            }
+            AstNodeType::Expression(Expression::FunctionExpression(_)) => {
+                write!(f, "FunctionExpression")
+            }
+            AstNodeType::Expression(Expression::IfExpression(_)) => write!(f, "IfExpression"),
+            AstNodeType::Expression(Expression::MatchExpression(_)) => {
+                write!(f, "MatchExpression")
+            }
         }
     }
 }
@@ -119,14 +170,50 @@ impl PartialEq for AstNodeType {
 #[derive(Debug, Clone)]
 pub enum Expression {
     StringLiteral(StringLiteral),
+    TemplateString(TemplateString),
     Number(Number),
     Bool(Bool),
     Identifier(Identifier),
     BinaryExpression(BinaryExpression),
+    UnaryExpression(UnaryExpression),
     CallExpression(CallExpression),
+    IndexExpression(IndexExpression),
+    MemberExpression(MemberExpression),
+    RangeExpression(RangeExpression),
+    Vector(Vector),
+    Object(Object),
+    StructExpression(StructExpression),
+    FunctionExpression(FunctionExpression),
+    IfExpression(Box<IfStatement>),
+    MatchExpression(Box<MatchExpression>),
     Nothing(Nothing),
 }
 
+impl Expression {
+    pub fn span(&self) -> &Span {
+        match self {
+            Expression::StringLiteral(v) => &v.span,
+            Expression::TemplateString(v) => &v.span,
+            Expression::Number(v) => &v.span,
+            Expression::Bool(v) => &v.span,
+            Expression::Identifier(v) => &v.span,
+            Expression::BinaryExpression(v) => &v.span,
+            Expression::UnaryExpression(v) => &v.span,
+            Expression::CallExpression(v) => &v.span,
+            Expression::IndexExpression(v) => &v.span,
+            Expression::MemberExpression(v) => &v.span,
+            Expression::RangeExpression(v) => &v.span,
+            Expression::Vector(v) => &v.span,
+            Expression::Object(v) => &v.span,
+            Expression::StructExpression(v) => &v.span,
+            Expression::FunctionExpression(v) => &v.span,
+            Expression::IfExpression(v) => &v.span,
+            Expression::MatchExpression(v) => &v.span,
+            Expression::Nothing(v) => &v.span,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Type {
     String,
@@ -1,11 +1,12 @@
+use crate::core::span::Span;
+
 #[derive(Debug, Clone)]
 pub struct Nothing {
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl Nothing {
-    pub fn new(at: usize, line: usize) -> Nothing {
-        Nothing { at, line }
+    pub fn new(span: Span) -> Nothing {
+        Nothing { span }
     }
 }
@@ -1,20 +1,20 @@
+use crate::core::span::Span;
+
 #[derive(Debug, Clone)]
 pub struct Number {
     pub value: f64,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl Number {
-    pub fn new(value: f64, at: usize, line: usize) -> Number {
-        Number { value, at, line }
+    pub fn new(value: f64, span: Span) -> Number {
+        Number { value, span }
     }
 
-    pub fn from_string(value: String, at: usize, line: usize) -> Option<Number> {
+    pub fn from_string(value: String, span: Span) -> Option<Number> {
         value.parse::<f64>().ok().map(|number| Number {
             value: number,
-            at,
-            line,
+            span,
         })
     }
 }
@@ -0,0 +1,21 @@
+use crate::core::span::Span;
+
+use super::{identifier::Identifier, Expression};
+
+#[derive(Debug, Clone)]
+pub struct Object {
+    pub properties: Vec<(Identifier, Expression)>,
+    pub span: Span,
+}
+
+impl Object {
+    pub fn new(span: Span) -> Object {
+        Object {
+            properties: vec![],
+            span,
+        }
+    }
+    pub fn add_property(&mut self, key: Identifier, value: Expression) {
+        self.properties.push((key, value));
+    }
+}
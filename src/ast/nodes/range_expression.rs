@@ -0,0 +1,16 @@
+use crate::core::span::Span;
+
+use super::Expression;
+
+#[derive(Debug, Clone)]
+pub struct RangeExpression {
+    pub start: Box<Expression>,
+    pub end: Box<Expression>,
+    pub span: Span,
+}
+
+impl RangeExpression {
+    pub fn new(start: Box<Expression>, end: Box<Expression>, span: Span) -> RangeExpression {
+        RangeExpression { start, end, span }
+    }
+}
@@ -1,14 +1,15 @@
+use crate::core::span::Span;
+
 use super::Expression;
 
 #[derive(Debug, Clone)]
 pub struct ReturnStatement {
     pub value: Expression,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl ReturnStatement {
-    pub fn new(value: Expression, at: usize, line: usize) -> ReturnStatement {
-        ReturnStatement { value, at, line }
+    pub fn new(value: Expression, span: Span) -> ReturnStatement {
+        ReturnStatement { value, span }
     }
 }
@@ -1,12 +1,13 @@
+use crate::core::span::Span;
+
 #[derive(Debug, Clone)]
 pub struct StringLiteral {
     pub value: String,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl StringLiteral {
-    pub fn new(value: String, at: usize, line: usize) -> StringLiteral {
-        StringLiteral { value, at, line }
+    pub fn new(value: String, span: Span) -> StringLiteral {
+        StringLiteral { value, span }
     }
 }
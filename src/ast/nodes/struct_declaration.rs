@@ -0,0 +1,21 @@
+use crate::core::span::Span;
+
+use super::identifier::Identifier;
+
+#[derive(Debug, Clone)]
+pub struct StructDeclaration {
+    pub identifier: Identifier,
+    // fields keep their optional type annotation
+    pub fields: Vec<Identifier>,
+    pub span: Span,
+}
+
+impl StructDeclaration {
+    pub fn new(identifier: Identifier, fields: Vec<Identifier>, span: Span) -> StructDeclaration {
+        StructDeclaration {
+            identifier,
+            fields,
+            span,
+        }
+    }
+}
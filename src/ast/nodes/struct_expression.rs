@@ -0,0 +1,21 @@
+use crate::core::span::Span;
+
+use super::{identifier::Identifier, object::Object};
+
+// Point { x: 1, y: 2 }
+#[derive(Debug, Clone)]
+pub struct StructExpression {
+    pub identifier: Identifier,
+    pub fields: Object,
+    pub span: Span,
+}
+
+impl StructExpression {
+    pub fn new(identifier: Identifier, fields: Object, span: Span) -> StructExpression {
+        StructExpression {
+            identifier,
+            fields,
+            span,
+        }
+    }
+}
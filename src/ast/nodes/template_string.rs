@@ -0,0 +1,15 @@
+use crate::core::span::Span;
+
+use super::Expression;
+
+#[derive(Debug, Clone)]
+pub struct TemplateString {
+    pub parts: Vec<Expression>,
+    pub span: Span,
+}
+
+impl TemplateString {
+    pub fn new(parts: Vec<Expression>, span: Span) -> TemplateString {
+        TemplateString { parts, span }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::core::span::Span;
+
+use super::Expression;
+
+#[derive(Debug, Clone)]
+pub struct UnaryExpression {
+    pub operator: String,
+    pub operand: Box<Expression>,
+    pub span: Span,
+}
+
+impl UnaryExpression {
+    pub fn new(operator: String, operand: Box<Expression>, span: Span) -> UnaryExpression {
+        UnaryExpression {
+            operator,
+            operand,
+            span,
+        }
+    }
+}
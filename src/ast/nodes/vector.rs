@@ -1,18 +1,18 @@
+use crate::core::span::Span;
+
 use super::Expression;
 
 #[derive(Debug, Clone)]
 pub struct Vector {
     pub children: Vec<Option<Expression>>,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
 }
 
 impl Vector {
-    pub fn new(at: usize, line: usize) -> Vector {
+    pub fn new(span: Span) -> Vector {
         Vector {
             children: vec![],
-            at,
-            line,
+            span,
         }
     }
     pub fn add_child(&mut self, node: Option<Expression>) {
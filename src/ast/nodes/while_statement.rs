@@ -1,20 +1,22 @@
-use super::{block::Block, Expression};
+use crate::core::span::Span;
+
+use super::{block::Block, identifier::Identifier, Expression};
 
 #[derive(Debug, Clone)]
 pub struct WhileStatement {
     pub condition: Expression,
     pub body: Block,
-    pub at: usize,
-    pub line: usize,
+    pub label: Option<Identifier>,
+    pub span: Span,
 }
 
 impl WhileStatement {
-    pub fn new(condition: Expression, body: Block, at: usize, line: usize) -> WhileStatement {
+    pub fn new(condition: Expression, body: Block, span: Span) -> WhileStatement {
         WhileStatement {
             condition,
             body,
-            at,
-            line,
+            label: None,
+            span,
         }
     }
 }
@@ -1,4 +1,7 @@
-use std::cell::Cell;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
 
 use crate::{
     ast::{
@@ -6,28 +9,61 @@ use crate::{
         block::Block,
         bool::Bool,
         call_expression::CallExpression,
+        enum_declaration::{EnumDeclaration, EnumVariant},
         function_declaration::FunctionDeclaration,
+        function_expression::FunctionExpression,
         group::Group,
         identifier::Identifier,
+        impl_declaration::ImplDeclaration,
         module::ModuleAst,
         number::Number,
         string_literal::StringLiteral,
+        struct_declaration::StructDeclaration,
+        struct_expression::StructExpression,
+        template_string::TemplateString,
+        unary_expression::UnaryExpression,
         AstNodeType, Expression, LexerToken, LexerTokenType,
     },
-    core::error::{self, ErrorType},
+    core::{
+        error::{EgoError, ErrorType},
+        span::Span,
+    },
 };
 
 use super::{
-    binary_expression::BinaryExpression, break_statement::BreakStatement,
-    else_statement::ElseStatement, if_statement::IfStatement, import_statement::ImportStatement,
-    nothing::Nothing, return_statement::ReturnStatement, vector::Vector,
-    while_statement::WhileStatement, Type,
+    binary_expression::BinaryExpression,
+    break_statement::BreakStatement,
+    continue_statement::ContinueStatement,
+    else_statement::{ElseBody, ElseStatement},
+    for_statement::ForStatement,
+    if_statement::IfStatement,
+    import_statement::ImportStatement,
+    index_expression::IndexExpression,
+    match_expression::{MatchArm, MatchExpression, Pattern, VariantPattern},
+    member_assignament::MemberAssignament,
+    member_expression::MemberExpression,
+    nothing::Nothing,
+    object::Object,
+    range_expression::RangeExpression,
+    return_statement::ReturnStatement,
+    vector::Vector,
+    while_statement::WhileStatement,
+    Type,
 };
 
 pub struct Module {
     module_name: String,
     tokens: Vec<LexerToken>,
     current: Cell<usize>,
+    errors: RefCell<Vec<EgoError>>,
+    // names declared on each block being parsed, used
+    // to reject reassignments of constants early
+    declarations: RefCell<Vec<HashMap<String, VarType>>>,
+    // labels of the loops being parsed
+    labels: RefCell<Vec<String>>,
+    // set while parsing the head of an if, while, for or match,
+    // where '{' after an identifier opens their body
+    condition: Cell<bool>,
 }
 
 impl Module {
@@ -36,67 +72,144 @@ impl Module {
             module_name,
             tokens,
             current: 0.into(),
+            errors: RefCell::new(vec![]),
+            declarations: RefCell::new(vec![HashMap::new()]),
+            labels: RefCell::new(vec![]),
+            condition: Cell::new(false),
+        }
+    }
+
+    // for callers that can only report a single error
+    pub fn parse(&mut self) -> Result<ModuleAst, EgoError> {
+        let (module_ast, mut errors) = self.parse_recovering();
+        if errors.is_empty() {
+            Ok(module_ast)
+        } else {
+            Err(errors.remove(0))
         }
     }
 
-    pub fn parse(&mut self) -> ModuleAst {
+    // keeps parsing after a syntax error, returns the
+    // partial ast and every error found on the way
+    pub fn parse_recovering(&mut self) -> (ModuleAst, Vec<EgoError>) {
         let module = ModuleAst::new(&self.module_name);
-        self.tree(module)
+        let module_ast = self.tree(module);
+        (module_ast, self.errors.take())
     }
 
     fn tree(&mut self, mut module_ast: ModuleAst) -> ModuleAst {
         while self.is_peekable() {
             let token = self.unsafe_peek();
+            let start = self.current();
 
-            match token.token_type {
-                LexerTokenType::LetKeyword => {
-                    let assignment_node = self.assignment_statement();
-                    module_ast.add_child(assignment_node);
-                }
-                LexerTokenType::FnKeyword => {
-                    let function_node = self.function_declaration();
-                    module_ast.add_child(function_node);
-                }
-                LexerTokenType::Identifier => {
-                    let identifier_node = self.identifier();
-                    module_ast.add_child(identifier_node);
-                }
-                LexerTokenType::OpenCurlyBrace => {
-                    let block_node = self.block();
-                    module_ast.add_child(block_node);
-                }
-                LexerTokenType::IfKeyword => {
-                    let if_node = self.if_statement();
-                    module_ast.add_child(if_node);
-                }
-                LexerTokenType::WhileKeyword => {
-                    let while_node = self.while_statement();
-                    module_ast.add_child(while_node);
-                }
-                LexerTokenType::ImportKeyword => {
-                    let import_node = self.import_statement();
-                    module_ast.add_child(import_node);
+            let node = match token.token_type {
+                LexerTokenType::LetKeyword | LexerTokenType::ConstKeyword => {
+                    self.assignment_statement()
                 }
-                _ => {
+                LexerTokenType::FnKeyword => self.function_declaration(),
+                LexerTokenType::StructKeyword => self.struct_declaration(),
+                LexerTokenType::ImplKeyword => self.impl_declaration(),
+                LexerTokenType::EnumKeyword => self.enum_declaration(),
+                LexerTokenType::MatchKeyword => self.match_statement(),
+                LexerTokenType::Identifier => self.identifier(),
+                LexerTokenType::OpenCurlyBrace => self.block(),
+                LexerTokenType::IfKeyword => self.if_statement(),
+                LexerTokenType::WhileKeyword => self.while_statement(),
+                LexerTokenType::ForKeyword => self.for_statement(),
+                LexerTokenType::ImportKeyword => self.import_statement(),
+                LexerTokenType::Number
+                | LexerTokenType::StringLiteral
+                | LexerTokenType::TemplateStart
+                | LexerTokenType::TrueKeyword
+                | LexerTokenType::FalseKeyword
+                | LexerTokenType::NothingKeyword
+                | LexerTokenType::OpenParenthesis
+                | LexerTokenType::OpenSquareBracket
+                | LexerTokenType::SubtractOperator
+                | LexerTokenType::NotOperator => self.expression(),
+                LexerTokenType::EndOfStatement => {
                     self.next();
+                    continue;
                 }
+                LexerTokenType::BreakKeyword | LexerTokenType::ContinueKeyword => {
+                    Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        "'break' and 'continue' are only valid inside a loop",
+                        Some(token.span.clone()),
+                    ))
+                }
+                _ => Err(EgoError::new(
+                    ErrorType::SyntaxError,
+                    format!("Unexpected token '{}'", token.value).as_str(),
+                    Some(token.span.clone()),
+                )),
+            };
+
+            match node {
+                Ok(node) => module_ast.add_child(node),
+                Err(e) => self.recover(e, start),
             }
         }
 
         module_ast
     }
 
+    // Error recovery:
+    fn recover(&self, error: EgoError, start: usize) {
+        self.errors.borrow_mut().push(error);
+
+        // the failed statement must consume something
+        // or the same error would be found again
+        if self.current() == start {
+            self.next();
+        }
+        self.synchronize();
+    }
+
+    // skips tokens until the start of the next statement or
+    // line, a '}' is left for the block that is being parsed
+    fn synchronize(&self) {
+        while self.is_peekable() {
+            let token = self.unsafe_peek();
+            if self.current() > 0 && token.span.line > self.previous_span().line {
+                return;
+            }
+
+            match token.token_type {
+                LexerTokenType::EndOfStatement => {
+                    self.next();
+                    return;
+                }
+                LexerTokenType::CloseCurlyBrace
+                | LexerTokenType::LetKeyword
+                | LexerTokenType::ConstKeyword
+                | LexerTokenType::FnKeyword
+                | LexerTokenType::StructKeyword
+                | LexerTokenType::ImplKeyword
+                | LexerTokenType::EnumKeyword
+                | LexerTokenType::MatchKeyword
+                | LexerTokenType::IfKeyword
+                | LexerTokenType::WhileKeyword
+                | LexerTokenType::ForKeyword
+                | LexerTokenType::ImportKeyword
+                | LexerTokenType::ReturnKeyword
+                | LexerTokenType::BreakKeyword
+                | LexerTokenType::ContinueKeyword => return,
+                _ => self.next(),
+            }
+        }
+    }
+
     // Index handlers:
-    fn peek(&self, token: &str) -> &LexerToken {
+    fn peek(&self, token: &str) -> Result<&LexerToken, EgoError> {
         if self.is_peekable() {
-            &self.tokens[self.current.get()]
+            Ok(&self.tokens[self.current.get()])
         } else {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::ParsingError,
                 format!("Expected '{token}' but got and early end of module").as_str(),
-                None,
-            );
-            std::process::exit(1);
+                self.tokens.last().map(|token| token.span.clone()),
+            ));
         }
     }
 
@@ -120,15 +233,10 @@ impl Module {
         }
     }
 
+    // stays at the end of the module, callers check is_peekable
     fn next(&self) {
-        if self.tokens.len() >= (self.current.get() + 1) {
-            self.current.set(self.current.get() + 1);
-        } else {
-            error::throw(
-                ErrorType::ParsingError,
-                "Peeked an out of bounds token",
-                Some(self.unsafe_peek().line),
-            )
+        if self.current() < self.tokens.len() {
+            self.current.set(self.current() + 1);
         }
     }
 
@@ -136,20 +244,60 @@ impl Module {
         self.current.get()
     }
 
+    // span of the last consumed token
+    fn previous_span(&self) -> Span {
+        self.tokens[self.current() - 1].span.clone()
+    }
+
+    // Declarations:
+    fn declare(&self, identifier: &Identifier, var_type: VarType) {
+        if let Some(scope) = self.declarations.borrow_mut().last_mut() {
+            scope.insert(identifier.name.clone(), var_type);
+        }
+    }
+
+    // the innermost declaration of the identifier is the one assigned
+    fn check_reassignment(&self, identifier: &Identifier) -> Result<(), EgoError> {
+        let declarations = self.declarations.borrow();
+        let var_type = declarations
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&identifier.name));
+
+        if let Some(VarType::Const) = var_type {
+            return Err(EgoError::new(
+                ErrorType::ConstantReassignmentError,
+                format!(
+                    "Cannot assign to '{}' because it is a constant",
+                    identifier.name
+                )
+                .as_str(),
+                Some(identifier.span.clone()),
+            ));
+        }
+        Ok(())
+    }
+
     // {}
-    fn block(&self) -> AstNodeType {
-        let mut block_node = Block::new();
+    fn block(&self) -> Result<AstNodeType, EgoError> {
+        self.declarations.borrow_mut().push(HashMap::new());
+        let block = self.in_condition(false, || self.block_statements());
+        self.declarations.borrow_mut().pop();
+        block
+    }
 
+    fn block_statements(&self) -> Result<AstNodeType, EgoError> {
         // check '{'
-        let token = self.unsafe_peek();
+        let token = self.peek("{")?;
+        let mut block_node = Block::new(token.span.clone());
         if token.token_type == LexerTokenType::OpenCurlyBrace {
             self.next();
         } else {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::SyntaxError,
                 format!("Unexpected token '{}' in block opening", token.value).as_str(),
-                Some(token.line),
-            )
+                Some(token.span.clone()),
+            ));
         }
 
         // get inside block ast nodes & check '}'
@@ -157,80 +305,97 @@ impl Module {
 
         while self.is_peekable() {
             let token = self.unsafe_peek();
+            let start = self.current();
 
             // offset & current are incremented inside each type
             // to avoid "tokens[overflowed_index]"" if loops ends
             // before a '{'
-            match token.token_type {
+            let node = match token.token_type {
                 LexerTokenType::CloseCurlyBrace => {
                     // consume '}'
                     self.next();
                     closed = true;
                     break; // break block loop since it reaches the end
                 }
-                LexerTokenType::LetKeyword => {
-                    let assignment_node = self.assignment_statement();
-                    block_node.add_child(assignment_node);
-                }
-                LexerTokenType::FnKeyword => {
-                    let function_node = self.function_declaration();
-                    block_node.add_child(function_node);
-                }
-                LexerTokenType::Identifier => {
-                    let identifier_node = self.identifier();
-                    block_node.add_child(identifier_node);
-                }
-                LexerTokenType::OpenCurlyBrace => {
-                    let inner_block_node = self.block();
-                    block_node.add_child(inner_block_node);
-                }
-                LexerTokenType::IfKeyword => {
-                    let if_node = self.if_statement();
-                    block_node.add_child(if_node);
-                }
-                LexerTokenType::WhileKeyword => {
-                    let while_node = self.while_statement();
-                    block_node.add_child(while_node);
-                }
-                LexerTokenType::ReturnKeyword => {
-                    let return_node = self.return_statement();
-                    block_node.add_child(return_node);
+                LexerTokenType::LetKeyword | LexerTokenType::ConstKeyword => {
+                    self.assignment_statement()
                 }
+                LexerTokenType::FnKeyword => self.function_declaration(),
+                LexerTokenType::StructKeyword => self.struct_declaration(),
+                LexerTokenType::ImplKeyword => self.impl_declaration(),
+                LexerTokenType::EnumKeyword => self.enum_declaration(),
+                LexerTokenType::MatchKeyword => self.match_statement(),
+                LexerTokenType::Identifier => self.identifier(),
+                LexerTokenType::OpenCurlyBrace => self.block(),
+                LexerTokenType::IfKeyword => self.if_statement(),
+                LexerTokenType::WhileKeyword => self.while_statement(),
+                LexerTokenType::ForKeyword => self.for_statement(),
+                LexerTokenType::ReturnKeyword => self.return_statement(),
+                // the last expression of a block is its value
+                LexerTokenType::Number
+                | LexerTokenType::StringLiteral
+                | LexerTokenType::TemplateStart
+                | LexerTokenType::TrueKeyword
+                | LexerTokenType::FalseKeyword
+                | LexerTokenType::NothingKeyword
+                | LexerTokenType::OpenParenthesis
+                | LexerTokenType::OpenSquareBracket
+                | LexerTokenType::SubtractOperator
+                | LexerTokenType::NotOperator => self.expression(),
                 LexerTokenType::BreakKeyword => {
-                    let token = self.unsafe_peek();
                     self.next(); // consume 'break'
-                    block_node.add_child(AstNodeType::BreakStatement(BreakStatement::new(
-                        token.at, token.line,
-                    )))
+                    self.jump_label(&token.span).map(|label| {
+                        let span = self.previous_span();
+                        AstNodeType::BreakStatement(BreakStatement::new(
+                            label,
+                            token.span.to(&span),
+                        ))
+                    })
                 }
-                _ => {
-                    error::throw(
-                        ErrorType::SyntaxError,
-                        format!(
-                            "Unexpected token '{}' inside block {{..}}", // generic error for unexpected codes for block parsing
-                            token.value,
-                        )
-                        .as_str(),
-                        Some(token.line),
-                    );
+                LexerTokenType::ContinueKeyword => {
+                    self.next(); // consume 'continue'
+                    self.jump_label(&token.span).map(|label| {
+                        let span = self.previous_span();
+                        AstNodeType::ContinueStatement(ContinueStatement::new(
+                            label,
+                            token.span.to(&span),
+                        ))
+                    })
                 }
+                _ => Err(EgoError::new(
+                    ErrorType::SyntaxError,
+                    format!(
+                        "Unexpected token '{}' inside block {{..}}", // generic error for unexpected codes for block parsing
+                        token.value,
+                    )
+                    .as_str(),
+                    Some(token.span.clone()),
+                )),
+            };
+
+            // errors inside the block don't end it, parsing
+            // goes on from the next statement
+            match node {
+                Ok(node) => block_node.add_child(node),
+                Err(e) => self.recover(e, start),
             }
         }
 
         // non closed Block
         if !closed {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::SyntaxError,
                 "Expected '}' for block close",
-                Some(token.line),
-            );
+                Some(token.span.clone()),
+            ));
         }
 
-        AstNodeType::Block(block_node)
+        block_node.span = block_node.span.to(&self.previous_span());
+        Ok(AstNodeType::Block(block_node))
     }
 
     // (a, b, c)
-    fn group(&self, context: Option<&str>) -> AstNodeType {
+    fn group(&self, context: Option<&str>) -> Result<AstNodeType, EgoError> {
         // where am i
         let context_msg = match context {
             Some(str) => format!(" in {}", str),
@@ -238,17 +403,17 @@ impl Module {
         };
 
         let group_token = self.unsafe_peek();
-        let mut group_node = Group::new(group_token.at, group_token.line);
+        let mut group_node = Group::new(group_token.span.clone());
 
         // check '('
         if group_token.token_type == LexerTokenType::OpenParenthesis {
             self.next()
         } else {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::SyntaxError,
                 format!("Unexpected token '{}'{}", group_token.value, context_msg).as_str(),
-                Some(group_token.line),
-            )
+                Some(group_token.span.clone()),
+            ));
         }
 
         // get arguments & check ')'
@@ -278,22 +443,42 @@ impl Module {
                     closed = true;
                     break;
                 }
+                // two elements without a comma between them
+                _ if last_token.is_some() && last_token != Some(LexerTokenType::Comma) => break,
                 _ => {
-                    let node = self.parse_comparison();
+                    let node = self.parse_or()?;
                     match node {
                         Expression::Identifier(_) => last_token = Some(LexerTokenType::Identifier),
                         Expression::Bool(_) => last_token = Some(LexerTokenType::TrueKeyword),
                         Expression::Number(_) => last_token = Some(LexerTokenType::Number),
                         Expression::Nothing(_) => last_token = Some(LexerTokenType::NothingKeyword),
-                        Expression::StringLiteral(_) => {
+                        Expression::StringLiteral(_) | Expression::TemplateString(_) => {
                             last_token = Some(LexerTokenType::StringLiteral)
                         }
                         Expression::CallExpression(_) => {
                             last_token = Some(LexerTokenType::Identifier)
                         }
-                        Expression::BinaryExpression(_) => {
+                        Expression::BinaryExpression(_) | Expression::UnaryExpression(_) => {
                             last_token = Some(LexerTokenType::Number)
                         }
+                        Expression::IndexExpression(_) => {
+                            last_token = Some(LexerTokenType::CloseSquareBracket)
+                        }
+                        Expression::Vector(_) => {
+                            last_token = Some(LexerTokenType::CloseSquareBracket)
+                        }
+                        Expression::MemberExpression(_) => {
+                            last_token = Some(LexerTokenType::Identifier)
+                        }
+                        Expression::Object(_) | Expression::StructExpression(_) => {
+                            last_token = Some(LexerTokenType::CloseCurlyBrace)
+                        }
+                        Expression::RangeExpression(_) => last_token = Some(LexerTokenType::Number),
+                        Expression::FunctionExpression(_)
+                        | Expression::IfExpression(_)
+                        | Expression::MatchExpression(_) => {
+                            last_token = Some(LexerTokenType::CloseCurlyBrace)
+                        }
                     }
                     group_node.add_child(Some(node));
                 }
@@ -302,38 +487,39 @@ impl Module {
 
         // non closed CallExpression
         if !closed {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::SyntaxError,
-                format!("Expected ')' {}", context_msg).as_str(),
-                Some(group_node.line),
-            )
+                format!("Expected ')'{}", context_msg).as_str(),
+                Some(group_node.span.clone()),
+            ));
         };
 
         // consume ')'
         self.next();
-        AstNodeType::Group(group_node)
+        group_node.span = group_node.span.to(&self.previous_span());
+        Ok(AstNodeType::Group(group_node))
     }
 
     // [a, b, x]
-    fn vector(&self, context: Option<&str>) -> AstNodeType {
+    fn vector(&self, context: Option<&str>) -> Result<AstNodeType, EgoError> {
         // where am i
         let context_msg = match context {
             Some(str) => format!(" in {}", str),
             _ => "".to_string(),
         };
 
-        let group_token = self.unsafe_peek();
-        let mut vector_node = Vector::new(group_token.at, group_token.line);
+        let group_token = self.peek("[")?;
+        let mut vector_node = Vector::new(group_token.span.clone());
 
         // check '['
         if group_token.token_type == LexerTokenType::OpenSquareBracket {
             self.next()
         } else {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::SyntaxError,
                 format!("Unexpected token '{}'{}", group_token.value, context_msg).as_str(),
-                Some(group_token.line),
-            )
+                Some(group_token.span.clone()),
+            ));
         }
 
         // get arguments & check ']'
@@ -360,22 +546,42 @@ impl Module {
                     closed = true;
                     break;
                 }
+                // two elements without a comma between them
+                _ if last_token.is_some() && last_token != Some(LexerTokenType::Comma) => break,
                 _ => {
-                    let node = self.parse_comparison();
+                    let node = self.parse_or()?;
                     match node {
                         Expression::Identifier(_) => last_token = Some(LexerTokenType::Identifier),
                         Expression::Bool(_) => last_token = Some(LexerTokenType::TrueKeyword),
                         Expression::Number(_) => last_token = Some(LexerTokenType::Number),
                         Expression::Nothing(_) => last_token = Some(LexerTokenType::NothingKeyword),
-                        Expression::StringLiteral(_) => {
+                        Expression::StringLiteral(_) | Expression::TemplateString(_) => {
                             last_token = Some(LexerTokenType::StringLiteral)
                         }
                         Expression::CallExpression(_) => {
                             last_token = Some(LexerTokenType::Identifier)
                         }
-                        Expression::BinaryExpression(_) => {
+                        Expression::BinaryExpression(_) | Expression::UnaryExpression(_) => {
                             last_token = Some(LexerTokenType::Number)
                         }
+                        Expression::IndexExpression(_) => {
+                            last_token = Some(LexerTokenType::CloseSquareBracket)
+                        }
+                        Expression::Vector(_) => {
+                            last_token = Some(LexerTokenType::CloseSquareBracket)
+                        }
+                        Expression::MemberExpression(_) => {
+                            last_token = Some(LexerTokenType::Identifier)
+                        }
+                        Expression::Object(_) | Expression::StructExpression(_) => {
+                            last_token = Some(LexerTokenType::CloseCurlyBrace)
+                        }
+                        Expression::RangeExpression(_) => last_token = Some(LexerTokenType::Number),
+                        Expression::FunctionExpression(_)
+                        | Expression::IfExpression(_)
+                        | Expression::MatchExpression(_) => {
+                            last_token = Some(LexerTokenType::CloseCurlyBrace)
+                        }
                     }
                     vector_node.add_child(Some(node));
                 }
@@ -384,23 +590,23 @@ impl Module {
 
         // non closed CallExpression
         if !closed {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::SyntaxError,
-                format!("Expected ']' {}", context_msg).as_str(),
-                Some(vector_node.line),
-            )
+                format!("Expected ']'{}", context_msg).as_str(),
+                Some(vector_node.span.clone()),
+            ));
         };
 
         // consume ']'
         self.next();
-        AstNodeType::Vector(vector_node)
+        vector_node.span = vector_node.span.to(&self.previous_span());
+        Ok(AstNodeType::Vector(vector_node))
     }
 
     // let a = 20
-    fn assignment_statement(&self) -> AstNodeType {
+    fn assignment_statement(&self) -> Result<AstNodeType, EgoError> {
         let token = self.unsafe_peek();
-        let at = token.at;
-        let line = token.line;
+        let span = token.span.clone();
 
         // get assignment type: mutable or constant or reassignment
         let var_type = match token.value.as_str() {
@@ -416,8 +622,8 @@ impl Module {
         };
 
         // consume identifier
-        let token = self.peek("<Identifier>");
-        let mut identifier_node = Identifier::new(token.value.clone(), token.at, token.line);
+        let token = self.peek("<Identifier>")?;
+        let mut identifier_node = Identifier::new(token.value.clone(), token.span.clone());
 
         // check if is 'let a:' || 'let a ='
         self.next();
@@ -427,83 +633,83 @@ impl Module {
             if token.token_type != LexerTokenType::AssignmentOperator
                 && token.token_type != LexerTokenType::Colon
             {
-                error::throw(
+                return Err(EgoError::new(
                     ErrorType::SyntaxError,
                     format!("Expected '=' but got '{}'", token.value).as_str(),
-                    Some(token.line),
-                )
+                    Some(token.span.clone()),
+                ));
             };
         }
 
         // get type anotation or none
-        let type_annotation = self.type_annotation();
+        let type_annotation = self.type_annotation("=")?;
         identifier_node.set_annotation(type_annotation);
 
         // check next token is '='
-        let token = self.peek("=");
+        let token = self.peek("=")?;
         if token.token_type != LexerTokenType::AssignmentOperator {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::SyntaxError,
                 format!("Expected '=' but got '{}'", token.value).as_str(),
-                Some(token.line),
-            )
+                Some(token.span.clone()),
+            ));
         };
 
         self.next();
-        let expr = self.parse_comparison();
+        let expr = self.parse_or()?;
         // static type checking
         if let Some(annotation) = type_annotation {
             match &expr {
                 Expression::Bool(_) => {
                     if annotation != Type::Bool {
-                        error::throw(
+                        return Err(EgoError::new(
                             ErrorType::TypeError,
                             format!(
                                 "Annotation of type '{}' differs from assigned 'bool' value",
                                 annotation.to_string()
                             )
                             .as_str(),
-                            Some(token.line),
-                        )
+                            Some(token.span.clone()),
+                        ));
                     }
                 }
                 Expression::StringLiteral(_) => {
                     if annotation != Type::String {
-                        error::throw(
+                        return Err(EgoError::new(
                             ErrorType::TypeError,
                             format!(
                                 "Annotation of type '{}' differs from assigned 'string' value",
                                 annotation.to_string()
                             )
                             .as_str(),
-                            Some(token.line),
-                        )
+                            Some(token.span.clone()),
+                        ));
                     }
                 }
                 Expression::Number(_) => {
                     if annotation != Type::Number {
-                        error::throw(
+                        return Err(EgoError::new(
                             ErrorType::TypeError,
                             format!(
                                 "Annotation of type '{}' differs from assigned 'number' value",
                                 annotation.to_string()
                             )
                             .as_str(),
-                            Some(token.line),
-                        )
+                            Some(token.span.clone()),
+                        ));
                     }
                 }
                 Expression::Nothing(_) => {
                     if annotation != Type::Nothing {
-                        error::throw(
+                        return Err(EgoError::new(
                             ErrorType::TypeError,
                             format!(
                                 "Annotation of type '{}' differs from assigned 'nothing' value",
                                 annotation.to_string()
                             )
                             .as_str(),
-                            Some(token.line),
-                        )
+                            Some(token.span.clone()),
+                        ));
                     }
                 }
                 _ => {}
@@ -512,265 +718,1024 @@ impl Module {
 
         // check for final semicolon
         if self.is_peekable() {
-            if self.peek(";").token_type == LexerTokenType::EndOfStatement {
+            if self.peek(";")?.token_type == LexerTokenType::EndOfStatement {
                 // consume ';'
                 self.next();
             }
         }
 
-        AstNodeType::AssignamentStatement(AssignamentNode::new(
+        match var_type {
+            VarType::None => self.check_reassignment(&identifier_node)?,
+            VarType::Let | VarType::Const => self.declare(&identifier_node, var_type),
+        }
+
+        Ok(AstNodeType::AssignamentStatement(AssignamentNode::new(
             identifier_node,
             expr,
             var_type,
-            at,
-            line,
-        ))
+            span.to(&self.previous_span()),
+        )))
     }
 
     // fn a() {...}
-    fn function_declaration(&self) -> AstNodeType {
+    fn function_declaration(&self) -> Result<AstNodeType, EgoError> {
+        self.function().map(AstNodeType::FunctionDeclaration)
+    }
+
+    fn function(&self) -> Result<FunctionDeclaration, EgoError> {
         // consume 'fn' keyword
+        let fn_span = self.unsafe_peek().span.clone();
         self.next();
 
         // consume function identifier
-        let token = self.peek("<Identifier>");
-        let identifier_node = Identifier::new(token.value.clone(), token.at, token.line);
+        let token = self.peek("<Identifier>")?;
+        let identifier_node = Identifier::new(token.value.clone(), token.span.clone());
+        self.declare(&identifier_node, VarType::Let);
         self.next();
 
-        // consume arguments
-        self.next(); // consume '('
-        let mut arguments: Vec<Identifier> = vec![];
-        let mut last_token = LexerTokenType::OpenParenthesis;
-        let mut closed = false;
+        let arguments = self.parameters()?;
+        let return_type = self.type_annotation("{")?;
+        let function_body = self.function_body(&arguments)?;
 
-        while self.is_peekable() {
-            let token = self.unsafe_peek();
+        Ok(FunctionDeclaration::new(
+            identifier_node,
+            arguments,
+            return_type,
+            function_body,
+            fn_span.to(&self.previous_span()),
+        ))
+    }
 
+    // struct Point { x: number, y: number }
+    fn struct_declaration(&self) -> Result<AstNodeType, EgoError> {
+        // consume 'struct' keyword
+        let struct_span = self.unsafe_peek().span.clone();
+        self.next();
+
+        let identifier_node = self.type_name("struct")?;
+        self.declare(&identifier_node, VarType::Let);
+
+        // consume '{'
+        let token = self.peek("{")?;
+        if token.token_type != LexerTokenType::OpenCurlyBrace {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!(
+                    "Expected '{{' but got '{}' in struct declaration",
+                    token.value
+                )
+                .as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+        self.next();
+
+        let mut fields: Vec<Identifier> = vec![];
+        let mut last_token = LexerTokenType::OpenCurlyBrace;
+        loop {
+            let token = self.peek("}")?;
             match token.token_type {
-                LexerTokenType::Comma => {
-                    // '(' and '<identifier>'
-                    if last_token != LexerTokenType::Comma {
-                        last_token = LexerTokenType::Comma;
-                        self.next();
-                    } else {
-                        error::throw(
-                            ErrorType::SyntaxError,
-                            format!("Unexpected token '{}' in function arguments", token.value)
-                                .as_str(),
-                            Some(token.line),
-                        )
-                    }
+                LexerTokenType::CloseCurlyBrace => {
+                    self.next(); // consume '}'
+                    break;
                 }
-                LexerTokenType::CloseParenthesis => {
-                    if last_token != LexerTokenType::Comma {
-                        closed = true;
-                        self.next();
-                        break;
-                    } else {
-                        error::throw(
+                LexerTokenType::Comma if last_token == LexerTokenType::Identifier => {
+                    last_token = LexerTokenType::Comma;
+                    self.next();
+                }
+                LexerTokenType::Identifier if last_token != LexerTokenType::Identifier => {
+                    if fields.iter().any(|field| field.name == token.value) {
+                        return Err(EgoError::new(
                             ErrorType::SyntaxError,
-                            format!("Unexpected token ',' before closing function arguments")
-                                .as_str(),
-                            Some(token.line),
-                        )
+                            format!(
+                                "Field '{}' is declared more than once in struct '{}'",
+                                token.value, identifier_node.name
+                            )
+                            .as_str(),
+                            Some(token.span.clone()),
+                        ));
                     }
-                }
-                LexerTokenType::Identifier => {
-                    arguments.push(Identifier::new(token.value.clone(), token.at, token.line));
-                    last_token = LexerTokenType::Identifier;
+
+                    let mut field = Identifier::new(token.value.clone(), token.span.clone());
                     self.next();
+                    field.set_annotation(self.type_annotation("}")?);
+                    fields.push(field);
+                    last_token = LexerTokenType::Identifier;
+                }
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        format!("Unexpected token '{}' in struct declaration", token.value)
+                            .as_str(),
+                        Some(token.span.clone()),
+                    ));
                 }
-                _ => error::throw(
-                    ErrorType::SyntaxError,
-                    format!("Unexpected token '{}' in function arguments", token.value).as_str(),
-                    Some(token.line),
-                ),
             }
         }
 
-        // non closed CallExpression
-        if !closed {
-            error::throw(
-                ErrorType::SyntaxError,
-                format!("Expected ')' to close function arguments").as_str(),
-                Some(token.line),
-            )
-        };
-
-        // check for block
-        let token = self.peek("{");
-        let block_node = self.block();
-        let function_body = match block_node {
-            AstNodeType::Block(b) => b,
-            _ => {
-                error::throw(
-                    ErrorType::ParsingError,
-                    "Expected blockNode as function body",
-                    Some(token.line),
-                );
-                std::process::exit(1);
-            }
-        };
-
-        AstNodeType::FunctionDeclaration(FunctionDeclaration::new(
+        Ok(AstNodeType::StructDeclaration(StructDeclaration::new(
             identifier_node,
-            arguments,
-            function_body,
-            token.at,
-            token.line,
-        ))
+            fields,
+            struct_span.to(&self.previous_span()),
+        )))
     }
 
-    // if (true) {...}
-    fn if_statement(&self) -> AstNodeType {
-        // consume 'if' keyword
-        let token = self.unsafe_peek();
-        let at = token.at;
-        let line = token.line;
-
-        // consume expression
+    // impl Point { fn len(self) {...} }
+    fn impl_declaration(&self) -> Result<AstNodeType, EgoError> {
+        // consume 'impl' keyword
+        let impl_span = self.unsafe_peek().span.clone();
         self.next();
-        let expr = self.expression();
-        let expr_node = match expr {
-            AstNodeType::Expression(b) => b,
-            _ => {
-                error::throw(
-                    ErrorType::ParsingError,
-                    "Expected expression after if",
-                    Some(token.line),
-                );
-                std::process::exit(1);
-            }
-        };
+
+        let identifier_node = self.type_name("impl")?;
 
         // consume '{'
-        let token = self.peek("{");
+        let token = self.peek("{")?;
         if token.token_type != LexerTokenType::OpenCurlyBrace {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::SyntaxError,
-                format!("Unexpected token '{}' in if statement", token.value).as_str(),
-                Some(token.line),
-            )
+                format!("Expected '{{' but got '{}' in impl block", token.value).as_str(),
+                Some(token.span.clone()),
+            ));
         }
+        self.next();
 
-        let block = self.block();
-        let block_node = match block {
-            AstNodeType::Block(b) => b,
-            _ => {
-                error::throw(
-                    ErrorType::ParsingError,
-                    "Expected blockNode as if arm",
-                    Some(token.line),
-                );
-                std::process::exit(1);
-            }
-        };
+        // methods are reached through the struct, they
+        // are not declared in the surrounding scope
+        self.declarations.borrow_mut().push(HashMap::new());
+        let methods = self.methods();
+        self.declarations.borrow_mut().pop();
 
-        // if there is else statement
-        let mut else_node = None;
-        if self.is_peekable() && self.peek("else").token_type == LexerTokenType::ElseKeyword {
-            let token = self.unsafe_peek();
-            let at = token.at;
-            let line = token.line;
-
-            self.next(); // {
-            let token = self.peek("{");
-            let block = self.block();
-            let block_node = match block {
-                AstNodeType::Block(b) => b,
+        Ok(AstNodeType::ImplDeclaration(ImplDeclaration::new(
+            identifier_node,
+            methods?,
+            impl_span.to(&self.previous_span()),
+        )))
+    }
+
+    fn methods(&self) -> Result<Vec<FunctionDeclaration>, EgoError> {
+        let mut methods = vec![];
+        loop {
+            let token = self.peek("}")?;
+            match token.token_type {
+                LexerTokenType::CloseCurlyBrace => {
+                    self.next(); // consume '}'
+                    return Ok(methods);
+                }
+                LexerTokenType::FnKeyword => methods.push(self.function()?),
                 _ => {
-                    error::throw(
-                        ErrorType::ParsingError,
-                        "Expected blockNode as else arm",
-                        Some(token.line),
-                    );
-                    std::process::exit(1);
+                    return Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        format!("Expected a method but got '{}' in impl block", token.value)
+                            .as_str(),
+                        Some(token.span.clone()),
+                    ));
                 }
-            };
-
-            else_node = Some(ElseStatement::new(block_node, at, line));
+            }
         }
-
-        AstNodeType::IfStatement(IfStatement::new(expr_node, block_node, else_node, at, line))
     }
 
-    // while (true) {...}
-    fn while_statement(&self) -> AstNodeType {
-        // consume 'while' keyword
-        let token = self.unsafe_peek();
-        let at = token.at;
-        let line = token.line;
-
-        // consume expression
+    // enum State { Idle, Running(speed: number) }
+    fn enum_declaration(&self) -> Result<AstNodeType, EgoError> {
+        // consume 'enum' keyword
+        let enum_span = self.unsafe_peek().span.clone();
         self.next();
-        let expr = self.expression();
-        let expr_node = match expr {
-            AstNodeType::Expression(b) => b,
-            _ => {
-                error::throw(
-                    ErrorType::ParsingError,
-                    "Expected expression after while",
-                    Some(token.line),
-                );
-                std::process::exit(1);
-            }
-        };
+
+        let identifier_node = self.type_name("enum")?;
+        self.declare(&identifier_node, VarType::Let);
 
         // consume '{'
-        let token = self.peek("{");
+        let token = self.peek("{")?;
         if token.token_type != LexerTokenType::OpenCurlyBrace {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::SyntaxError,
                 format!(
-                    "Expected '{{' but got '{}' after while condition",
+                    "Expected '{{' but got '{}' in enum declaration",
                     token.value
                 )
                 .as_str(),
-                Some(token.line),
-            )
+                Some(token.span.clone()),
+            ));
         }
-
-        let block = self.block();
-        let block_node = match block {
-            AstNodeType::Block(b) => b,
-            _ => {
-                error::throw(
-                    ErrorType::ParsingError,
-                    "Expected Block {...} after while condition",
-                    Some(token.line),
-                );
-                std::process::exit(1);
-            }
-        };
-
-        AstNodeType::WhileStatement(WhileStatement::new(expr_node, block_node, at, line))
-    }
-
-    // import std/io.[read_input, read_file]
-    fn import_statement(&self) -> AstNodeType {
-        // consume 'import' keyword
-        let token = self.unsafe_peek();
-        let at = token.at;
-        let line = token.line;
-
-        // consume '<identifier>'
         self.next();
-        let token = self.peek("<identifier>");
-        if token.token_type != LexerTokenType::Identifier {
-            error::throw(
-                ErrorType::SyntaxError,
-                format!("Unexpected token '{}' after import keyword", token.value).as_str(),
-                Some(token.line),
-            )
-        }
-        let mut module = vec![token.value.clone()];
 
-        // consume ";" | "/"
+        let mut variants: Vec<EnumVariant> = vec![];
+        let mut last_token = LexerTokenType::OpenCurlyBrace;
+        loop {
+            let token = self.peek("}")?;
+            match token.token_type {
+                LexerTokenType::CloseCurlyBrace => {
+                    self.next(); // consume '}'
+                    break;
+                }
+                LexerTokenType::Comma if last_token == LexerTokenType::Identifier => {
+                    last_token = LexerTokenType::Comma;
+                    self.next();
+                }
+                LexerTokenType::Identifier if last_token != LexerTokenType::Identifier => {
+                    if variants
+                        .iter()
+                        .any(|variant| variant.identifier.name == token.value)
+                    {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!(
+                                "Variant '{}' is declared more than once in enum '{}'",
+                                token.value, identifier_node.name
+                            )
+                            .as_str(),
+                            Some(token.span.clone()),
+                        ));
+                    }
+
+                    let variant = Identifier::new(token.value.clone(), token.span.clone());
+                    self.next();
+                    let fields = if self.is_peekable()
+                        && self.unsafe_peek().token_type == LexerTokenType::OpenParenthesis
+                    {
+                        self.variant_fields(&variant)?
+                    } else {
+                        vec![]
+                    };
+                    variants.push(EnumVariant::new(variant, fields));
+                    last_token = LexerTokenType::Identifier;
+                }
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        format!("Unexpected token '{}' in enum declaration", token.value).as_str(),
+                        Some(token.span.clone()),
+                    ));
+                }
+            }
+        }
+
+        Ok(AstNodeType::EnumDeclaration(EnumDeclaration::new(
+            identifier_node,
+            variants,
+            enum_span.to(&self.previous_span()),
+        )))
+    }
+
+    // (speed: number, label)
+    fn variant_fields(&self, variant: &Identifier) -> Result<Vec<Identifier>, EgoError> {
+        // consume '('
+        self.next();
+
+        let mut fields: Vec<Identifier> = vec![];
+        let mut last_token = LexerTokenType::OpenParenthesis;
+        loop {
+            let token = self.peek(")")?;
+            match token.token_type {
+                LexerTokenType::CloseParenthesis if last_token != LexerTokenType::Comma => {
+                    self.next(); // consume ')'
+                    return Ok(fields);
+                }
+                LexerTokenType::Comma if last_token == LexerTokenType::Identifier => {
+                    last_token = LexerTokenType::Comma;
+                    self.next();
+                }
+                LexerTokenType::Identifier if last_token != LexerTokenType::Identifier => {
+                    if fields.iter().any(|field| field.name == token.value) {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!(
+                                "Field '{}' is declared more than once in variant '{}'",
+                                token.value, variant.name
+                            )
+                            .as_str(),
+                            Some(token.span.clone()),
+                        ));
+                    }
+
+                    let mut field = Identifier::new(token.value.clone(), token.span.clone());
+                    self.next();
+                    field.set_annotation(self.type_annotation(")")?);
+                    fields.push(field);
+                    last_token = LexerTokenType::Identifier;
+                }
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        format!(
+                            "Unexpected token '{}' in the fields of variant '{}'",
+                            token.value, variant.name
+                        )
+                        .as_str(),
+                        Some(token.span.clone()),
+                    ));
+                }
+            }
+        }
+    }
+
+    // name after 'struct', 'impl' and 'enum'
+    fn type_name(&self, keyword: &str) -> Result<Identifier, EgoError> {
+        let token = self.peek("<Identifier>")?;
+        if token.token_type != LexerTokenType::Identifier {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!(
+                    "Expected a name after '{keyword}' but got '{}'",
+                    token.value
+                )
+                .as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+        self.next();
+        Ok(Identifier::new(token.value.clone(), token.span.clone()))
+    }
+
+    // an identifier followed by '{ field:' starts an instance, the
+    // struct can be declared in another module. 'Point {}' is one too
+    // unless it is in a condition, like 'while running {}'
+    fn is_struct_start(&self, brace: usize) -> bool {
+        let token_type = |offset: usize| {
+            self.tokens
+                .get(brace + offset)
+                .map(|token| token.token_type.clone())
+        };
+        if token_type(0) != Some(LexerTokenType::OpenCurlyBrace) {
+            return false;
+        }
+
+        match (token_type(1), token_type(2), token_type(3)) {
+            // 'outer: while' is a labeled loop inside a block
+            (
+                Some(LexerTokenType::Identifier),
+                Some(LexerTokenType::Colon),
+                Some(LexerTokenType::WhileKeyword | LexerTokenType::ForKeyword),
+            ) => false,
+            (Some(LexerTokenType::Identifier), Some(LexerTokenType::Colon), _) => true,
+            (Some(LexerTokenType::CloseCurlyBrace), _, _) => !self.condition.get(),
+            _ => false,
+        }
+    }
+
+    fn in_condition<T>(&self, condition: bool, parse: impl FnOnce() -> T) -> T {
+        let outer = self.condition.replace(condition);
+        let result = parse();
+        self.condition.set(outer);
+        result
+    }
+
+    // fn (a, b) {...}
+    fn function_expression(&self) -> Result<Expression, EgoError> {
+        // consume 'fn' keyword
+        let fn_span = self.unsafe_peek().span.clone();
+        self.next();
+
+        let arguments = self.parameters()?;
+        let return_type = self.type_annotation("{")?;
+        let function_body = self.function_body(&arguments)?;
+
+        Ok(Expression::FunctionExpression(FunctionExpression::new(
+            arguments,
+            return_type,
+            function_body,
+            fn_span.to(&self.previous_span()),
+        )))
+    }
+
+    // (a, b: number)
+    fn parameters(&self) -> Result<Vec<Identifier>, EgoError> {
+        let token = self.peek("(")?;
+        if token.token_type != LexerTokenType::OpenParenthesis {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!("Expected '(' but got '{}'", token.value).as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+
+        // consume arguments
+        self.next(); // consume '('
+        let mut arguments: Vec<Identifier> = vec![];
+        let mut last_token = LexerTokenType::OpenParenthesis;
+        let mut closed = false;
+
+        while self.is_peekable() {
+            let token = self.unsafe_peek();
+
+            match token.token_type {
+                LexerTokenType::Comma => {
+                    // '(' and '<identifier>'
+                    if last_token != LexerTokenType::Comma {
+                        last_token = LexerTokenType::Comma;
+                        self.next();
+                    } else {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!("Unexpected token '{}' in function arguments", token.value)
+                                .as_str(),
+                            Some(token.span.clone()),
+                        ));
+                    }
+                }
+                LexerTokenType::CloseParenthesis => {
+                    if last_token != LexerTokenType::Comma {
+                        closed = true;
+                        self.next();
+                        break;
+                    } else {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!("Unexpected token ',' before closing function arguments")
+                                .as_str(),
+                            Some(token.span.clone()),
+                        ));
+                    }
+                }
+                LexerTokenType::Identifier => {
+                    let mut parameter = Identifier::new(token.value.clone(), token.span.clone());
+                    self.next();
+                    parameter.set_annotation(self.type_annotation(")")?);
+                    arguments.push(parameter);
+                    last_token = LexerTokenType::Identifier;
+                }
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        format!("Unexpected token '{}' in function arguments", token.value)
+                            .as_str(),
+                        Some(token.span.clone()),
+                    ))
+                }
+            }
+        }
+
+        // non closed CallExpression
+        if !closed {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                "Expected ')' to close function arguments",
+                Some(token.span.clone()),
+            ));
+        };
+
+        Ok(arguments)
+    }
+
+    fn function_body(&self, parameters: &[Identifier]) -> Result<Block, EgoError> {
+        // parameters shadow outer declarations
+        let parameters = parameters
+            .iter()
+            .map(|parameter| (parameter.name.clone(), VarType::Let))
+            .collect();
+
+        // check for block
+        let token = self.peek("{")?;
+        self.declarations.borrow_mut().push(parameters);
+        // loops outside the function can't be left from inside it
+        let labels = self.labels.take();
+        let block_node = self.block();
+        self.labels.replace(labels);
+        self.declarations.borrow_mut().pop();
+        match block_node? {
+            AstNodeType::Block(b) => Ok(b),
+            _ => Err(EgoError::new(
+                ErrorType::ParsingError,
+                "Expected blockNode as function body",
+                Some(token.span.clone()),
+            )),
+        }
+    }
+
+    // if (true) {...}
+    fn if_statement(&self) -> Result<AstNodeType, EgoError> {
+        // consume 'if' keyword
+        let token = self.unsafe_peek();
+        let span = token.span.clone();
+
+        // consume expression
+        self.next();
+        let expr = self.in_condition(true, || self.expression())?;
+        let expr_node = match expr {
+            AstNodeType::Expression(b) => b,
+            _ => {
+                return Err(EgoError::new(
+                    ErrorType::ParsingError,
+                    "Expected expression after if",
+                    Some(token.span.clone()),
+                ));
+            }
+        };
+
+        // consume '{'
+        let token = self.peek("{")?;
+        if token.token_type != LexerTokenType::OpenCurlyBrace {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!("Unexpected token '{}' in if statement", token.value).as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+
+        let block = self.block()?;
+        let block_node = match block {
+            AstNodeType::Block(b) => b,
+            _ => {
+                return Err(EgoError::new(
+                    ErrorType::ParsingError,
+                    "Expected blockNode as if arm",
+                    Some(token.span.clone()),
+                ));
+            }
+        };
+
+        // if there is else statement
+        let mut else_node = None;
+        if self.is_peekable() && self.peek("else")?.token_type == LexerTokenType::ElseKeyword {
+            let token = self.unsafe_peek();
+            let span = token.span.clone();
+
+            self.next(); // consume 'else'
+            let token = self.peek("{")?;
+            let body = match token.token_type {
+                // else if ... {...} continues the chain
+                LexerTokenType::IfKeyword => match self.if_statement()? {
+                    AstNodeType::IfStatement(node) => ElseBody::If(Box::new(node)),
+                    _ => {
+                        return Err(EgoError::new(
+                            ErrorType::ParsingError,
+                            "Expected IfStatement as else arm",
+                            Some(token.span.clone()),
+                        ));
+                    }
+                },
+                _ => match self.block()? {
+                    AstNodeType::Block(b) => ElseBody::Block(b),
+                    _ => {
+                        return Err(EgoError::new(
+                            ErrorType::ParsingError,
+                            "Expected blockNode as else arm",
+                            Some(token.span.clone()),
+                        ));
+                    }
+                },
+            };
+
+            else_node = Some(ElseStatement::new(body, span.to(&self.previous_span())));
+        }
+
+        Ok(AstNodeType::IfStatement(IfStatement::new(
+            expr_node,
+            block_node,
+            else_node,
+            span.to(&self.previous_span()),
+        )))
+    }
+
+    // a match used as a statement is not the start of a
+    // longer expression, like an if statement
+    fn match_statement(&self) -> Result<AstNodeType, EgoError> {
+        let node = self.match_expression()?;
+        Ok(AstNodeType::Expression(Expression::MatchExpression(
+            Box::new(node),
+        )))
+    }
+
+    // match state { State.Idle => 0, State.Running(speed) => speed, _ => -1 }
+    fn match_expression(&self) -> Result<MatchExpression, EgoError> {
+        // consume 'match' keyword
+        let span = self.unsafe_peek().span.clone();
+        self.next();
+
+        let subject = self.in_condition(true, || self.parse_or())?;
+
+        // consume '{'
+        let token = self.peek("{")?;
+        if token.token_type != LexerTokenType::OpenCurlyBrace {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!(
+                    "Expected '{{' but got '{}' in match expression",
+                    token.value
+                )
+                .as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+        self.next();
+
+        let mut arms = vec![];
+        loop {
+            let token = self.peek("}")?;
+            if token.token_type == LexerTokenType::CloseCurlyBrace {
+                self.next(); // consume '}'
+                break;
+            }
+            arms.push(self.match_arm()?);
+        }
+
+        Ok(MatchExpression::new(
+            subject,
+            arms,
+            span.to(&self.previous_span()),
+        ))
+    }
+
+    // pattern => expression | pattern => {...}
+    fn match_arm(&self) -> Result<MatchArm, EgoError> {
+        let pattern = self.pattern()?;
+
+        // consume '=>'
+        let token = self.peek("=>")?;
+        if token.token_type != LexerTokenType::FatArrow {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!("Expected '=>' after the pattern but got '{}'", token.value).as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+        self.next();
+
+        // names bound by the pattern are only declared inside the arm
+        let mut bindings = vec![];
+        pattern_bindings(&pattern, &mut bindings);
+        let mut declarations = HashMap::new();
+        for binding in bindings {
+            if declarations
+                .insert(binding.name.clone(), VarType::Let)
+                .is_some()
+            {
+                return Err(EgoError::new(
+                    ErrorType::SyntaxError,
+                    format!(
+                        "Name '{}' is bound more than once in the same pattern",
+                        binding.name
+                    )
+                    .as_str(),
+                    Some(binding.span.clone()),
+                ));
+            }
+        }
+
+        // '{' always starts a block, not an object
+        self.declarations.borrow_mut().push(declarations);
+        let body = if self.peek("<expression>")?.token_type == LexerTokenType::OpenCurlyBrace {
+            match self.block() {
+                Ok(AstNodeType::Block(block)) => Ok(block),
+                Ok(_) => Err(EgoError::new(
+                    ErrorType::ParsingError,
+                    "Expected blockNode as match arm",
+                    Some(token.span.clone()),
+                )),
+                Err(e) => Err(e),
+            }
+        } else {
+            self.parse_or().map(|expression| {
+                let mut block = Block::new(expression.span().clone());
+                block.add_child(AstNodeType::Expression(expression));
+                block
+            })
+        };
+        self.declarations.borrow_mut().pop();
+        let body = body?;
+
+        // arms can be separated by commas
+        if self.is_peekable() && self.unsafe_peek().token_type == LexerTokenType::Comma {
+            self.next();
+        }
+
+        Ok(MatchArm::new(pattern, body))
+    }
+
+    // 1 | "idle" | -1 | true | nothing | _ | name | State.Running(speed, _)
+    fn pattern(&self) -> Result<Pattern, EgoError> {
+        let token = self.peek("<pattern>")?;
+        match token.token_type {
+            LexerTokenType::Identifier if token.value == "_" => {
+                self.next(); // consume '_'
+                Ok(Pattern::Wildcard(token.span.clone()))
+            }
+            LexerTokenType::Identifier => {
+                self.next(); // consume the name
+                let identifier = Identifier::new(token.value.clone(), token.span.clone());
+                if self.is_peekable() && self.unsafe_peek().token_type == LexerTokenType::Dot {
+                    self.variant_pattern(identifier)
+                } else {
+                    Ok(Pattern::Binding(identifier))
+                }
+            }
+            LexerTokenType::Number
+            | LexerTokenType::StringLiteral
+            | LexerTokenType::TrueKeyword
+            | LexerTokenType::FalseKeyword
+            | LexerTokenType::NothingKeyword
+            | LexerTokenType::SubtractOperator => match self.parse_unary()? {
+                literal @ (Expression::Number(_)
+                | Expression::StringLiteral(_)
+                | Expression::Bool(_)
+                | Expression::Nothing(_)) => Ok(Pattern::Literal(literal)),
+                expression => Err(EgoError::new(
+                    ErrorType::SyntaxError,
+                    "Only literals, variants, names and '_' are valid patterns",
+                    Some(expression.span().clone()),
+                )),
+            },
+            _ => Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!("Unexpected token '{}' in match pattern", token.value).as_str(),
+                Some(token.span.clone()),
+            )),
+        }
+    }
+
+    // State.Idle | State.Running(speed)
+    fn variant_pattern(&self, enumeration: Identifier) -> Result<Pattern, EgoError> {
+        // consume '.'
+        self.next();
+
+        let token = self.peek("<Identifier>")?;
+        if token.token_type != LexerTokenType::Identifier {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!(
+                    "Expected a variant name after '{}.' but got '{}'",
+                    enumeration.name, token.value
+                )
+                .as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+        self.next(); // consume variant name
+        let variant = Identifier::new(token.value.clone(), token.span.clone());
+
+        let mut patterns = vec![];
+        if self.is_peekable() && self.unsafe_peek().token_type == LexerTokenType::OpenParenthesis {
+            self.next(); // consume '('
+            if self.peek(")")?.token_type == LexerTokenType::CloseParenthesis {
+                self.next(); // consume ')'
+            } else {
+                loop {
+                    patterns.push(self.pattern()?);
+                    let token = self.peek(")")?;
+                    match token.token_type {
+                        LexerTokenType::Comma => self.next(),
+                        LexerTokenType::CloseParenthesis => {
+                            self.next(); // consume ')'
+                            break;
+                        }
+                        _ => {
+                            return Err(EgoError::new(
+                                ErrorType::SyntaxError,
+                                format!(
+                                    "Expected ',' or ')' but got '{}' in variant pattern",
+                                    token.value
+                                )
+                                .as_str(),
+                                Some(token.span.clone()),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        let span = enumeration.span.to(&self.previous_span());
+        Ok(Pattern::Variant(VariantPattern::new(
+            enumeration,
+            variant,
+            patterns,
+            span,
+        )))
+    }
+
+    // for item in items {...} | for i in 0..10 {...}
+    fn for_statement(&self) -> Result<AstNodeType, EgoError> {
+        // consume 'for' keyword
+        let token = self.unsafe_peek();
+        let span = token.span.clone();
+        self.next();
+
+        // consume item identifier
+        let token = self.peek("<Identifier>")?;
+        if token.token_type != LexerTokenType::Identifier {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!("Expected an identifier but got '{}' after for", token.value).as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+        let item = Identifier::new(token.value.clone(), token.span.clone());
+        self.next();
+
+        // consume 'in' keyword
+        let token = self.peek("in")?;
+        if token.token_type != LexerTokenType::InKeyword {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!("Expected 'in' but got '{}'", token.value).as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+        self.next();
+
+        // iterable or start..end
+        let mut iterable = self.in_condition(true, || self.parse_or())?;
+        if self.is_peekable() && self.unsafe_peek().token_type == LexerTokenType::RangeOperator {
+            // consume '..'
+            self.next();
+            let end = self.in_condition(true, || self.parse_or())?;
+            let range_span = iterable.span().to(end.span());
+            iterable = Expression::RangeExpression(RangeExpression::new(
+                Box::new(iterable),
+                Box::new(end),
+                range_span,
+            ));
+        }
+
+        // consume '{'
+        let token = self.peek("{")?;
+        if token.token_type != LexerTokenType::OpenCurlyBrace {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!("Expected '{{' but got '{}' after for iterable", token.value).as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+
+        // the item is declared inside the loop body
+        let declarations = HashMap::from([(item.name.clone(), VarType::Let)]);
+        self.declarations.borrow_mut().push(declarations);
+        let block = self.block();
+        self.declarations.borrow_mut().pop();
+        let block_node = match block? {
+            AstNodeType::Block(b) => b,
+            _ => {
+                return Err(EgoError::new(
+                    ErrorType::ParsingError,
+                    "Expected Block {...} after for iterable",
+                    Some(token.span.clone()),
+                ));
+            }
+        };
+
+        Ok(AstNodeType::ForStatement(ForStatement::new(
+            item,
+            iterable,
+            block_node,
+            span.to(&self.previous_span()),
+        )))
+    }
+
+    // outer: while (true) {...} | outer: for x in xs {...}
+    fn labeled_loop(&self) -> Result<AstNodeType, EgoError> {
+        let token = self.unsafe_peek();
+        let label = Identifier::new(token.value.clone(), token.span.clone());
+        // consume label and ':'
+        self.next();
+        self.next();
+
+        let token = self.peek("while")?;
+        self.labels.borrow_mut().push(label.name.clone());
+        let node = match token.token_type {
+            LexerTokenType::WhileKeyword => self.while_statement(),
+            LexerTokenType::ForKeyword => self.for_statement(),
+            _ => Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!(
+                    "Expected a loop after label '{}' but got '{}'",
+                    label.name, token.value
+                )
+                .as_str(),
+                Some(token.span.clone()),
+            )),
+        };
+        self.labels.borrow_mut().pop();
+
+        match node? {
+            AstNodeType::WhileStatement(mut node) => {
+                node.label = Some(label);
+                Ok(AstNodeType::WhileStatement(node))
+            }
+            AstNodeType::ForStatement(mut node) => {
+                node.label = Some(label);
+                Ok(AstNodeType::ForStatement(node))
+            }
+            _ => Err(EgoError::new(
+                ErrorType::ParsingError,
+                "Expected a loop after label",
+                Some(token.span.clone()),
+            )),
+        }
+    }
+
+    // label after break or continue, only if it's on the same line
+    fn jump_label(&self, keyword: &Span) -> Result<Option<Identifier>, EgoError> {
+        if !self.is_peekable() {
+            return Ok(None);
+        }
+        let token = self.unsafe_peek();
+        if token.token_type != LexerTokenType::Identifier || token.span.line != keyword.line {
+            return Ok(None);
+        }
+
+        if !self.labels.borrow().contains(&token.value) {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!("Unknown loop label '{}'", token.value).as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+        self.next();
+        Ok(Some(Identifier::new(
+            token.value.clone(),
+            token.span.clone(),
+        )))
+    }
+
+    // while (true) {...}
+    fn while_statement(&self) -> Result<AstNodeType, EgoError> {
+        // consume 'while' keyword
+        let token = self.unsafe_peek();
+        let span = token.span.clone();
+
+        // consume expression
+        self.next();
+        let expr = self.in_condition(true, || self.expression())?;
+        let expr_node = match expr {
+            AstNodeType::Expression(b) => b,
+            _ => {
+                return Err(EgoError::new(
+                    ErrorType::ParsingError,
+                    "Expected expression after while",
+                    Some(token.span.clone()),
+                ));
+            }
+        };
+
+        // consume '{'
+        let token = self.peek("{")?;
+        if token.token_type != LexerTokenType::OpenCurlyBrace {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!(
+                    "Expected '{{' but got '{}' after while condition",
+                    token.value
+                )
+                .as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+
+        let block = self.block()?;
+        let block_node = match block {
+            AstNodeType::Block(b) => b,
+            _ => {
+                return Err(EgoError::new(
+                    ErrorType::ParsingError,
+                    "Expected Block {...} after while condition",
+                    Some(token.span.clone()),
+                ));
+            }
+        };
+
+        Ok(AstNodeType::WhileStatement(WhileStatement::new(
+            expr_node,
+            block_node,
+            span.to(&self.previous_span()),
+        )))
+    }
+
+    // import std/io.[read_input, read_file]
+    fn import_statement(&self) -> Result<AstNodeType, EgoError> {
+        // consume 'import' keyword
+        let token = self.unsafe_peek();
+        let span = token.span.clone();
+
+        // consume '<identifier>'
         self.next();
-        let token = self.peek(";"); // with ; will be a valid statement
+        let token = self.peek("<identifier>")?;
+        if token.token_type != LexerTokenType::Identifier {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                format!("Unexpected token '{}' after import keyword", token.value).as_str(),
+                Some(token.span.clone()),
+            ));
+        }
+        let mut module = vec![token.value.clone()];
 
+        // consume ";" | "/"
+        self.next();
+        if !self.is_peekable() {
+            // import at the end of the module
+            return Ok(AstNodeType::ImportStatement(ImportStatement::new(
+                module,
+                vec![],
+                span.to(&self.previous_span()),
+            )));
+        }
+
+        let token = self.peek(";")?; // with ; will be a valid statement
         if token.token_type == LexerTokenType::EndOfStatement {
-            return AstNodeType::ImportStatement(ImportStatement::new(module, vec![], at, line));
+            self.next(); // consume ';'
+            return Ok(AstNodeType::ImportStatement(ImportStatement::new(
+                module,
+                vec![],
+                span.to(&self.previous_span()),
+            )));
         }
 
         // module/module/module
@@ -778,22 +1743,21 @@ impl Module {
             let mut last_token = LexerTokenType::Identifier;
 
             while self.is_peekable() {
-                let token = self.unsafe_peek();
+                let token = self.peek("<identifier>")?;
 
                 match token.token_type {
-                    LexerTokenType::Identifier => {
+                    // type keywords are valid module names: std/string
+                    LexerTokenType::Identifier
+                    | LexerTokenType::StringKeyword
+                    | LexerTokenType::NumberKeyword
+                    | LexerTokenType::BoolKeyword => {
                         if last_token == LexerTokenType::DivideOperator {
                             last_token = LexerTokenType::Identifier;
                             module.push(token.value.clone());
                             self.next();
                         } else {
-                            error::throw(
-                                ErrorType::ParsingError,
-                                format!("Unexpected token '{}' after an identifier", token.value)
-                                    .as_str(),
-                                Some(token.line),
-                            );
-                            std::process::exit(1);
+                            // next statement
+                            break;
                         }
                     }
                     LexerTokenType::DivideOperator => {
@@ -801,12 +1765,11 @@ impl Module {
                             last_token = LexerTokenType::DivideOperator;
                             self.next();
                         } else {
-                            error::throw(
+                            return Err(EgoError::new(
                                 ErrorType::ParsingError,
                                 format!("Unexpected token '{}' after '/'", token.value).as_str(),
-                                Some(token.line),
-                            );
-                            std::process::exit(1);
+                                Some(token.span.clone()),
+                            ));
                         }
                     }
                     _ => break,
@@ -815,126 +1778,291 @@ impl Module {
         }
 
         // consume ";" | "."
-        let token = self.peek(";");
-        match token.token_type {
-            // ;
-            LexerTokenType::EndOfStatement => {
-                return AstNodeType::ImportStatement(ImportStatement::new(
-                    module,
-                    vec![],
-                    at,
-                    line,
-                ));
-            }
+        if !self.is_peekable() {
+            return Ok(AstNodeType::ImportStatement(ImportStatement::new(
+                module,
+                vec![],
+                span.to(&self.previous_span()),
+            )));
+        }
+        let token = self.peek(".")?;
+        let members = match token.token_type {
             // .[member, member];
             LexerTokenType::Dot => {
                 // consume '.'
                 self.next();
-                self.vector(Some("import statement"))
+                let members_node = match self.vector(Some("import statement"))? {
+                    AstNodeType::Vector(v) => v,
+                    _ => {
+                        return Err(EgoError::new(
+                            ErrorType::ParsingError,
+                            "Unexpected node type in import statement, expected Vector type node",
+                            Some(token.span.clone()),
+                        ));
+                    }
+                };
+
+                let mut members = vec![];
+                for member in members_node.children {
+                    match member {
+                        Some(Expression::Identifier(identifier)) => members.push(identifier.name),
+                        _ => {
+                            return Err(EgoError::new(
+                                ErrorType::SyntaxError,
+                                "Expected only identifiers as imported members",
+                                Some(members_node.span.clone()),
+                            ));
+                        }
+                    }
+                }
+                members
             }
-            _ => {
-                error::throw(
-                    ErrorType::SyntaxError,
-                    format!(
-                        "Expected ';' but got '{}' as end of import statement",
-                        token.value
-                    )
-                    .as_str(),
-                    Some(token.line),
-                );
-                std::process::exit(1);
+            // ; or next statement
+            _ => vec![],
+        };
+
+        // check for final semicolon
+        if self.is_peekable() {
+            if self.peek(";")?.token_type == LexerTokenType::EndOfStatement {
+                // consume ';'
+                self.next();
             }
         }
+
+        Ok(AstNodeType::ImportStatement(ImportStatement::new(
+            module,
+            members,
+            span.to(&self.previous_span()),
+        )))
     }
 
     // return "hello";
-    fn return_statement(&self) -> AstNodeType {
+    fn return_statement(&self) -> Result<AstNodeType, EgoError> {
         // consume 'return' keyword
         let token = self.unsafe_peek();
-        let at = token.at;
-        let line = token.line;
+        let span = token.span.clone();
+
+        // consume expression
+        self.next();
+        let expression_node = self.parse_or()?;
+
+        // check for final semicolon
+        if self.is_peekable() {
+            if self.peek(";")?.token_type == LexerTokenType::EndOfStatement {
+                // consume ';'
+                self.next();
+            }
+        }
+
+        Ok(AstNodeType::ReturnStatement(ReturnStatement::new(
+            expression_node,
+            span.to(&self.previous_span()),
+        )))
+    }
+
+    // a | a() | a.value | a = 20 + a
+    fn identifier(&self) -> Result<AstNodeType, EgoError> {
+        let token = self.unsafe_peek();
+        // get the identifier
+        let identifier_node = Identifier::new(token.value.clone(), token.span.clone());
+        // check next token of the identifier without consuming
+        let token = self.peek_next();
+
+        let node = match token {
+            Some(next) => match next.token_type {
+                // [identifier value mutation]
+                LexerTokenType::AssignmentOperator => {
+                    // a = ...;
+                    self.assignment_statement()?
+                }
+                // [identifier calling or member mutation]
+                LexerTokenType::OpenParenthesis
+                | LexerTokenType::OpenSquareBracket
+                | LexerTokenType::Dot => {
+                    // a(); | a[0] = ...; | a.value = ...; | a.value;
+                    self.member_statement()?
+                }
+                // [identifier as a value]
+                LexerTokenType::CloseCurlyBrace
+                | LexerTokenType::AddOperator
+                | LexerTokenType::SubtractOperator
+                | LexerTokenType::MultiplyOperator
+                | LexerTokenType::DivideOperator
+                | LexerTokenType::IntDivideOperator
+                | LexerTokenType::ModuloOperator
+                | LexerTokenType::PowerOperator
+                | LexerTokenType::AndOperator
+                | LexerTokenType::OrOperator
+                | LexerTokenType::GreaterThanOperator
+                | LexerTokenType::LessThanOperator
+                | LexerTokenType::EqualityOperator
+                | LexerTokenType::NotEqualOperator
+                | LexerTokenType::GreaterThanOrEqualOperator
+                | LexerTokenType::LessThanOrEqualOperator => {
+                    // x } | x + 1
+                    self.expression()?
+                }
+                // [struct instance]
+                LexerTokenType::OpenCurlyBrace if self.is_struct_start(self.current() + 1) => {
+                    // Point { x: 1 }
+                    self.expression()?
+                }
+                // [loop label]
+                LexerTokenType::Colon => {
+                    // outer: while ... {...}
+                    self.labeled_loop()?
+                }
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        format!(
+                            "Unexpected token '{}' after '{}' identifier",
+                            next.value, identifier_node.name
+                        )
+                        .as_str(),
+                        Some(next.span.clone()),
+                    ));
+                }
+            },
+            None => {
+                // a; at the end of the module
+                self.next();
+                AstNodeType::Expression(Expression::Identifier(identifier_node))
+            }
+        };
+
+        Ok(node)
+    }
+
+    // a[0] = 20 | a.value = 20 | a.value | a()
+    fn member_statement(&self) -> Result<AstNodeType, EgoError> {
+        let token = self.unsafe_peek();
+        let span = token.span.clone();
+        let start = self.current();
+        let errors = self.errors.borrow().len();
+
+        // parse the accessed member as a factor so
+        // it stops right before the '='
+        let target = self.parse_factor()?;
+
+        let node = if self.is_peekable()
+            && self.unsafe_peek().token_type == LexerTokenType::AssignmentOperator
+        {
+            // consume '='
+            self.next();
+            let init = self.parse_or()?;
 
-        // consume expression
-        self.next();
-        let expression_node = self.parse_comparison();
+            match &target {
+                Expression::IndexExpression(_) | Expression::MemberExpression(_) => {
+                    if let Some(identifier) = root_identifier(&target) {
+                        self.check_reassignment(identifier)?;
+                    }
+                }
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        "Invalid left-hand side in member assignment",
+                        Some(span.clone()),
+                    ));
+                }
+            }
+
+            AstNodeType::MemberAssignament(MemberAssignament::new(
+                target,
+                init,
+                span.to(&self.previous_span()),
+            ))
+        } else if self.is_peekable() && is_binary_operator(&self.unsafe_peek().token_type) {
+            // not an assignment but the start of a longer
+            // expression like 'f(1) + 2', parse it again whole
+            self.current.set(start);
+            self.errors.borrow_mut().truncate(errors);
+            AstNodeType::Expression(self.parse_or()?)
+        } else {
+            AstNodeType::Expression(target)
+        };
 
         // check for final semicolon
         if self.is_peekable() {
-            if self.peek(";").token_type == LexerTokenType::EndOfStatement {
+            if self.peek(";")?.token_type == LexerTokenType::EndOfStatement {
                 // consume ';'
                 self.next();
             }
         }
 
-        AstNodeType::ReturnStatement(ReturnStatement::new(expression_node, at, line))
+        Ok(node)
     }
 
-    // a | a() | a.value | a = 20 + a
-    fn identifier(&self) -> AstNodeType {
-        let token = self.unsafe_peek();
-        // get the identifier
-        let identifier_node = Identifier::new(token.value.clone(), token.at, token.line);
-        // check next token of the identifier without consuming
-        let token = self.peek_next();
+    // (2 * 2) + 3
+    fn expression(&self) -> Result<AstNodeType, EgoError> {
+        let expr = self.parse_or()?;
+        Ok(AstNodeType::Expression(expr))
+    }
 
-        let node = match token {
-            Some(next) => match next.token_type {
-                // [identifier calling]
-                LexerTokenType::OpenParenthesis => {
-                    // a();
-                    let call_expresssion_node = self.call_expression();
-                    // check for final semicolon
-                    if self.is_peekable() {
-                        if self.peek(";").token_type == LexerTokenType::EndOfStatement {
-                            // consume ';'
-                            self.next();
-                        }
-                    }
+    // a || b
+    fn parse_or(&self) -> Result<Expression, EgoError> {
+        let mut node = self.parse_and()?;
 
-                    AstNodeType::Expression(call_expresssion_node)
-                }
-                // [identifier value mutation]
-                LexerTokenType::AssignmentOperator => {
-                    // a = ...;
-                    self.assignment_statement()
-                }
-                // [Property acess] should handle <a.value> here
-                //LexerTokenType::Dot => {}
-                _ => {
-                    error::throw(
-                        ErrorType::SyntaxError,
-                        format!(
-                            "Unexpected token '{}' after '{}' identifier",
-                            next.value, identifier_node.name
-                        )
-                        .as_str(),
-                        Some(next.line),
-                    );
-                    std::process::exit(1);
+        while self.is_peekable() {
+            let token = self.unsafe_peek();
+            match token.token_type {
+                LexerTokenType::OrOperator => {
+                    // consume the operator
+                    self.next();
+
+                    // get right node
+                    let right = self.parse_and()?;
+                    let span = node.span().to(right.span());
+                    node = Expression::BinaryExpression(BinaryExpression::new(
+                        token.value.clone(),
+                        Box::new(node),
+                        Box::new(right),
+                        span,
+                    ));
                 }
-            },
-            None => AstNodeType::Expression(Expression::Identifier(identifier_node)),
-        };
+                _ => break,
+            }
+        }
 
-        node
+        Ok(node)
     }
 
-    // (2 * 2) + 3
-    fn expression(&self) -> AstNodeType {
-        let expr = self.parse_comparison();
-        AstNodeType::Expression(expr)
+    // a && b
+    fn parse_and(&self) -> Result<Expression, EgoError> {
+        let mut node = self.parse_comparison()?;
+
+        while self.is_peekable() {
+            let token = self.unsafe_peek();
+            match token.token_type {
+                LexerTokenType::AndOperator => {
+                    // consume the operator
+                    self.next();
+
+                    // get right node
+                    let right = self.parse_comparison()?;
+                    let span = node.span().to(right.span());
+                    node = Expression::BinaryExpression(BinaryExpression::new(
+                        token.value.clone(),
+                        Box::new(node),
+                        Box::new(right),
+                        span,
+                    ));
+                }
+                _ => break,
+            }
+        }
+
+        Ok(node)
     }
 
     // 2 > 3
-    fn parse_comparison(&self) -> Expression {
-        let mut node = self.parse_expression();
+    fn parse_comparison(&self) -> Result<Expression, EgoError> {
+        let mut node = self.parse_expression()?;
 
         while self.is_peekable() {
             let token = self.unsafe_peek();
             match token.token_type {
-                LexerTokenType::AmpersandOperator
-                | LexerTokenType::OrOperator
-                | LexerTokenType::GreaterThanOperator
+                LexerTokenType::GreaterThanOperator
                 | LexerTokenType::LessThanOperator
                 | LexerTokenType::EqualityOperator
                 | LexerTokenType::NotEqualOperator
@@ -944,25 +2072,25 @@ impl Module {
                     self.next();
 
                     // get right node
-                    let right = self.parse_expression();
+                    let right = self.parse_expression()?;
+                    let span = node.span().to(right.span());
                     node = Expression::BinaryExpression(BinaryExpression::new(
                         token.value.clone(),
                         Box::new(node),
                         Box::new(right),
-                        token.at,
-                        token.line,
+                        span,
                     ));
                 }
                 _ => break,
             }
         }
 
-        node
+        Ok(node)
     }
 
     // 2 + 3 * 23
-    fn parse_expression(&self) -> Expression {
-        let mut node = self.parse_term();
+    fn parse_expression(&self) -> Result<Expression, EgoError> {
+        let mut node = self.parse_term()?;
         while self.is_peekable() {
             let token = self.unsafe_peek();
             match token.token_type {
@@ -971,217 +2099,598 @@ impl Module {
                     self.next();
 
                     // get right node
-                    let right = self.parse_term();
+                    let right = self.parse_term()?;
+                    let span = node.span().to(right.span());
                     node = Expression::BinaryExpression(BinaryExpression::new(
                         token.value.clone(),
                         Box::new(node),
                         Box::new(right),
-                        token.at,
-                        token.line,
+                        span,
                     ));
                 }
                 _ => break,
             }
         }
 
-        node
+        Ok(node)
     }
 
     // 2 * 4
-    fn parse_term(&self) -> Expression {
-        let mut node = self.parse_factor();
+    fn parse_term(&self) -> Result<Expression, EgoError> {
+        let mut node = self.parse_unary()?;
 
         while self.is_peekable() {
             let token = self.unsafe_peek();
             match token.token_type {
-                LexerTokenType::MultiplyOperator | LexerTokenType::DivideOperator => {
+                LexerTokenType::MultiplyOperator
+                | LexerTokenType::DivideOperator
+                | LexerTokenType::IntDivideOperator
+                | LexerTokenType::ModuloOperator => {
                     // consume the operator
                     self.next();
 
                     // get right node
-                    let right = self.parse_factor();
+                    let right = self.parse_unary()?;
+                    let span = node.span().to(right.span());
                     node = Expression::BinaryExpression(BinaryExpression::new(
                         token.value.clone(),
                         Box::new(node),
                         Box::new(right),
-                        token.at,
-                        token.line,
+                        span,
                     ));
                 }
                 _ => break,
             }
         }
 
-        node
+        Ok(node)
+    }
+
+    // -x | !done, looser than '**' so -2 ** 2 is -(2 ** 2)
+    fn parse_unary(&self) -> Result<Expression, EgoError> {
+        let token = self.peek("<expression>")?;
+        match token.token_type {
+            LexerTokenType::SubtractOperator | LexerTokenType::NotOperator => {
+                self.next(); // consume the operator
+
+                // the operand already includes its members and calls
+                let operand = self.parse_unary()?;
+                let span = token.span.to(operand.span());
+                match (token.token_type.clone(), operand) {
+                    // -5 is a number literal
+                    (LexerTokenType::SubtractOperator, Expression::Number(number)) => {
+                        Ok(Expression::Number(Number::new(-number.value, span)))
+                    }
+                    (_, operand) => Ok(Expression::UnaryExpression(UnaryExpression::new(
+                        token.value.clone(),
+                        Box::new(operand),
+                        span,
+                    ))),
+                }
+            }
+            _ => self.parse_power(),
+        }
+    }
+
+    // 2 ** 3 ** 2 is grouped from the right
+    fn parse_power(&self) -> Result<Expression, EgoError> {
+        let node = self.parse_factor()?;
+
+        if self.is_peekable() && self.unsafe_peek().token_type == LexerTokenType::PowerOperator {
+            let token = self.unsafe_peek();
+            // consume the operator
+            self.next();
+
+            // get right node, 2 ** -1 is allowed
+            let right = self.parse_unary()?;
+            let span = node.span().to(right.span());
+            return Ok(Expression::BinaryExpression(BinaryExpression::new(
+                token.value.clone(),
+                Box::new(node),
+                Box::new(right),
+                span,
+            )));
+        }
+
+        Ok(node)
     }
 
     // 2 | x | "Hi"
-    fn parse_factor(&self) -> Expression {
-        let token = self.unsafe_peek();
+    fn parse_factor(&self) -> Result<Expression, EgoError> {
+        let token = self.peek("<expression>")?;
         let expr = match token.token_type {
             LexerTokenType::OpenParenthesis => {
                 self.next(); // to consume the '('
-                let expr = self.parse_expression();
+                let expr = self.parse_or()?;
 
-                let scoped_token = self.peek(")");
+                let scoped_token = self.peek(")")?;
                 if scoped_token.token_type == LexerTokenType::CloseParenthesis {
                     self.next(); // to consume the ')'
                     expr
                 } else {
-                    error::throw(
+                    return Err(EgoError::new(
                         ErrorType::ParsingError,
                         format!("Unexpected token '{}', expected ')'", scoped_token.value).as_str(),
-                        Some(scoped_token.line),
-                    );
-                    std::process::exit(1);
+                        Some(scoped_token.span.clone()),
+                    ));
                 }
             }
             LexerTokenType::Number => {
-                let number_node = Number::from_string(token.value.clone(), token.at, token.line);
+                let number_node = Number::from_string(token.value.clone(), token.span.clone());
 
                 if let Some(node) = number_node {
                     self.next(); // consume number itself
                     Expression::Number(node)
                 } else {
-                    error::throw(
+                    return Err(EgoError::new(
                         ErrorType::ParsingError,
                         format!("Invalid token '{}' inside of a expression", token.value).as_str(),
-                        Some(token.line),
-                    );
-                    std::process::exit(1);
+                        Some(token.span.clone()),
+                    ));
                 }
             }
             LexerTokenType::TrueKeyword | LexerTokenType::FalseKeyword => {
                 let node = if let Ok(bool_value) = token.value.parse::<bool>() {
-                    Bool::new(bool_value, token.at, token.line)
+                    Bool::new(bool_value, token.span.clone())
                 } else {
-                    error::throw(
+                    return Err(EgoError::new(
                         ErrorType::ParsingError,
                         format!("Invalid token '{}' inside of a expression", token.value).as_str(),
-                        Some(token.line),
-                    );
-                    std::process::exit(1);
+                        Some(token.span.clone()),
+                    ));
                 };
 
                 self.next(); // consume keyword
                 Expression::Bool(node)
             }
+            LexerTokenType::TemplateStart => self.template_string()?,
             LexerTokenType::StringLiteral => {
                 self.next(); // consume string literal
                 Expression::StringLiteral(StringLiteral::new(
                     token.value.clone(),
-                    token.at,
-                    token.line,
+                    token.span.clone(),
                 ))
             }
             LexerTokenType::Identifier => {
-                // check if is identifier or identifier call expression
-                if let Some(next) = self.peek_next() {
-                    if next.token_type == LexerTokenType::OpenParenthesis {
-                        self.call_expression()
-                    } else {
-                        self.next();
-                        Expression::Identifier(Identifier::new(
-                            token.value.clone(),
-                            token.at,
-                            token.line,
-                        ))
-                    }
+                // calls are parsed as members of the identifier
+                self.next();
+                let identifier = Identifier::new(token.value.clone(), token.span.clone());
+                if self.is_struct_start(self.current()) {
+                    // Point { x: 1, y: 2 }
+                    let fields = self.object()?;
+                    let span = identifier.span.to(&fields.span);
+                    Expression::StructExpression(StructExpression::new(identifier, fields, span))
                 } else {
-                    self.next();
-                    Expression::Identifier(Identifier::new(
-                        token.value.clone(),
-                        token.at,
-                        token.line,
-                    ))
+                    Expression::Identifier(identifier)
+                }
+            }
+            LexerTokenType::FnKeyword => self.function_expression()?,
+            LexerTokenType::IfKeyword => match self.if_statement()? {
+                AstNodeType::IfStatement(node) => {
+                    if let Some(span) = if_leaving_jump(&node, &[], false) {
+                        return Err(leaving_expression_error(span));
+                    }
+                    Expression::IfExpression(Box::new(node))
+                }
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::ParsingError,
+                        "Unexpected node type in expression, expected IfStatement type node",
+                        Some(token.span.clone()),
+                    ));
+                }
+            },
+            LexerTokenType::MatchKeyword => {
+                let node = self.match_expression()?;
+                let jump = node
+                    .arms
+                    .iter()
+                    .find_map(|arm| leaving_jump(&arm.body.children, &[], false));
+                if let Some(span) = jump {
+                    return Err(leaving_expression_error(span));
                 }
+                Expression::MatchExpression(Box::new(node))
             }
             LexerTokenType::NothingKeyword => {
                 self.next(); // consume nothing keyword
-                Expression::Nothing(Nothing::new(token.at, token.line))
+                Expression::Nothing(Nothing::new(token.span.clone()))
             }
+            LexerTokenType::OpenCurlyBrace => Expression::Object(self.object()?),
+            LexerTokenType::OpenSquareBracket => match self.vector(None)? {
+                AstNodeType::Vector(vector_node) => Expression::Vector(vector_node),
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::ParsingError,
+                        "Unexpected node type in expression, expected Vector type node",
+                        Some(token.span.clone()),
+                    ));
+                }
+            },
             _ => {
-                error::throw(
-                    error::ErrorType::SyntaxError,
+                return Err(EgoError::new(
+                    ErrorType::SyntaxError,
                     format!("Invalid token '{}' inside of a expression", token.value).as_str(),
-                    Some(token.line),
-                );
-                std::process::exit(1);
+                    Some(token.span.clone()),
+                ));
             }
         };
 
-        expr
+        self.parse_member(expr)
     }
 
-    // print(a, b, c)
-    fn call_expression(&self) -> Expression {
-        // get the identifier
-        let identifier_token = self.unsafe_peek();
-        let identifier_node = Identifier::new(
-            identifier_token.value.clone(),
-            identifier_token.at,
-            identifier_token.line,
-        );
+    // "Hello {name}!"
+    fn template_string(&self) -> Result<Expression, EgoError> {
+        // consume template start
+        let token = self.unsafe_peek();
+        let span = token.span.clone();
+        self.next();
 
-        // consume identifier
+        let mut parts = vec![];
+        loop {
+            let token = self.peek("\"")?;
+            match token.token_type {
+                LexerTokenType::StringLiteral => {
+                    self.next(); // consume the text
+                    parts.push(Expression::StringLiteral(StringLiteral::new(
+                        token.value.clone(),
+                        token.span.clone(),
+                    )));
+                }
+                LexerTokenType::InterpolationStart => {
+                    self.next(); // consume '{'
+                    parts.push(self.parse_or()?);
+
+                    let token = self.peek("}")?;
+                    if token.token_type != LexerTokenType::InterpolationEnd {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!(
+                                "Expected '}}' to close the interpolation but got '{}'",
+                                token.value
+                            )
+                            .as_str(),
+                            Some(token.span.clone()),
+                        ));
+                    }
+                    self.next(); // consume '}'
+                }
+                LexerTokenType::TemplateEnd => {
+                    self.next(); // consume template end
+                    break;
+                }
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::ParsingError,
+                        format!("Unexpected token '{}' inside of a string", token.value).as_str(),
+                        Some(token.span.clone()),
+                    ));
+                }
+            }
+        }
+
+        Ok(Expression::TemplateString(TemplateString::new(parts, span)))
+    }
+
+    // a[0] | a[0][1] | a.value | a.value[0].name | a(1)(2) | a.f()
+    fn parse_member(&self, object: Expression) -> Result<Expression, EgoError> {
+        let mut node = object;
+
+        while self.is_peekable() {
+            let token = self.unsafe_peek();
+            match token.token_type {
+                LexerTokenType::OpenParenthesis => {
+                    node = self.call_expression(node)?;
+                }
+                LexerTokenType::OpenSquareBracket => {
+                    // consume '['
+                    self.next();
+                    let index = self.parse_or()?;
+
+                    let closing_token = self.peek("]")?;
+                    if closing_token.token_type != LexerTokenType::CloseSquareBracket {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!("Unexpected token '{}', expected ']'", closing_token.value)
+                                .as_str(),
+                            Some(closing_token.span.clone()),
+                        ));
+                    }
+                    // consume ']'
+                    self.next();
+
+                    let span = node.span().to(&self.previous_span());
+                    node = Expression::IndexExpression(IndexExpression::new(
+                        Box::new(node),
+                        Box::new(index),
+                        span,
+                    ));
+                }
+                LexerTokenType::Dot => {
+                    // consume '.'
+                    self.next();
+
+                    let property_token = self.peek("<Identifier>")?;
+                    if property_token.token_type != LexerTokenType::Identifier {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!(
+                                "Unexpected token '{}', expected a property name after '.'",
+                                property_token.value
+                            )
+                            .as_str(),
+                            Some(property_token.span.clone()),
+                        ));
+                    }
+                    // consume property name
+                    self.next();
+
+                    let span = node.span().to(&property_token.span);
+                    node = Expression::MemberExpression(MemberExpression::new(
+                        Box::new(node),
+                        Identifier::new(property_token.value.clone(), property_token.span.clone()),
+                        span,
+                    ));
+                }
+                _ => break,
+            }
+        }
+
+        Ok(node)
+    }
+
+    // { name: "ego", age: 3 }
+    fn object(&self) -> Result<Object, EgoError> {
+        let object_token = self.unsafe_peek();
+        let mut object_node = Object::new(object_token.span.clone());
+
+        // consume '{'
         self.next();
 
-        let arguments_node = self.group(Some(format!("{}()", identifier_node.name).as_str()));
+        let mut last_token = LexerTokenType::OpenCurlyBrace;
+        let mut closed = false;
+
+        while self.is_peekable() {
+            let token = self.unsafe_peek();
+
+            match token.token_type {
+                LexerTokenType::CloseCurlyBrace => {
+                    // consume '}'
+                    self.next();
+                    closed = true;
+                    break;
+                }
+                LexerTokenType::Comma => {
+                    if last_token == LexerTokenType::Comma
+                        || last_token == LexerTokenType::OpenCurlyBrace
+                    {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            "Unexpected token ',' in object literal",
+                            Some(token.span.clone()),
+                        ));
+                    }
+                    last_token = LexerTokenType::Comma;
+                    self.next();
+                }
+                LexerTokenType::Identifier => {
+                    if last_token == LexerTokenType::Identifier {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!("Expected ',' before '{}' in object literal", token.value)
+                                .as_str(),
+                            Some(token.span.clone()),
+                        ));
+                    }
+
+                    let key = Identifier::new(token.value.clone(), token.span.clone());
+                    self.next(); // consume key
+
+                    let colon_token = self.peek(":")?;
+                    if colon_token.token_type != LexerTokenType::Colon {
+                        return Err(EgoError::new(
+                            ErrorType::SyntaxError,
+                            format!(
+                                "Expected ':' but got '{}' after '{}' key",
+                                colon_token.value, key.name
+                            )
+                            .as_str(),
+                            Some(colon_token.span.clone()),
+                        ));
+                    }
+                    self.next(); // consume ':'
+
+                    let value = self.parse_or()?;
+                    object_node.add_property(key, value);
+                    last_token = LexerTokenType::Identifier;
+                }
+                _ => {
+                    return Err(EgoError::new(
+                        ErrorType::SyntaxError,
+                        format!("Unexpected token '{}' in object literal", token.value).as_str(),
+                        Some(token.span.clone()),
+                    ));
+                }
+            }
+        }
+
+        // non closed Object
+        if !closed {
+            return Err(EgoError::new(
+                ErrorType::SyntaxError,
+                "Expected '}' to close object literal",
+                Some(object_node.span.clone()),
+            ));
+        }
+
+        object_node.span = object_node.span.to(&self.previous_span());
+        Ok(object_node)
+    }
+
+    // print(a, b, c)
+    fn call_expression(&self, callee: Expression) -> Result<Expression, EgoError> {
+        let call_token = self.unsafe_peek();
+        let context = match &callee {
+            Expression::Identifier(identifier) => format!("{}()", identifier.name),
+            _ => "function call".to_string(),
+        };
+        let arguments_node = self.group(Some(context.as_str()))?;
 
         let arguments_node = if let AstNodeType::Group(arguments_node) = arguments_node {
             arguments_node
         } else {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::ParsingError,
                 "Unexpected node type in CallExpression, expected Group type node",
-                Some(identifier_token.line),
-            );
-            std::process::exit(1);
+                Some(call_token.span.clone()),
+            ));
         };
 
-        let at = identifier_node.at;
-        let line = identifier_node.line;
-        Expression::CallExpression(CallExpression::new(
-            identifier_node,
+        let span = callee.span().to(&self.previous_span());
+        Ok(Expression::CallExpression(CallExpression::new(
+            Box::new(callee),
             arguments_node,
-            at,
-            line,
-        ))
+            span,
+        )))
     }
 
     // : bool | : string | : number | : nothing
-    fn type_annotation(&self) -> Option<Type> {
-        if self.peek("=").token_type == LexerTokenType::Colon {
+    fn type_annotation(&self, expected: &str) -> Result<Option<Type>, EgoError> {
+        if self.peek(expected)?.token_type == LexerTokenType::Colon {
             // consume ':'
             self.next();
             if self.is_peekable() {
                 let possible_type = self.unsafe_peek();
                 self.next(); // consume 'annotated type'
                 match possible_type.token_type {
-                    LexerTokenType::NumberKeyword => Some(Type::Number),
-                    LexerTokenType::StringKeyword => Some(Type::String),
-                    LexerTokenType::BoolKeyword => Some(Type::Bool),
-                    LexerTokenType::NothingKeyword => Some(Type::Nothing),
+                    LexerTokenType::NumberKeyword => Ok(Some(Type::Number)),
+                    LexerTokenType::StringKeyword => Ok(Some(Type::String)),
+                    LexerTokenType::BoolKeyword => Ok(Some(Type::Bool)),
+                    LexerTokenType::NothingKeyword => Ok(Some(Type::Nothing)),
                     _ => {
-                        error::throw(
+                        return Err(EgoError::new(
                             ErrorType::InvalidTypeAnnotation,
                             format!("Expected type after ':' but got '{}'", possible_type.value)
                                 .as_str(),
-                            None,
-                        );
-                        std::process::exit(1);
+                            Some(possible_type.span.clone()),
+                        ));
                     }
                 }
             } else {
-                error::throw(
+                return Err(EgoError::new(
                     ErrorType::ParsingError,
                     format!("Expected type after ':' but got and early end of module").as_str(),
                     None,
-                );
-                std::process::exit(1);
+                ));
             }
         } else {
-            None
+            Ok(None)
+        }
+    }
+}
+
+// a in a[0].value
+fn root_identifier(expression: &Expression) -> Option<&Identifier> {
+    match expression {
+        Expression::Identifier(identifier) => Some(identifier),
+        Expression::IndexExpression(v) => root_identifier(&v.object),
+        Expression::MemberExpression(v) => root_identifier(&v.object),
+        _ => None,
+    }
+}
+
+// an if or match expression can't leave the function or loop around it
+fn leaving_expression_error(span: Span) -> EgoError {
+    EgoError::new(
+        ErrorType::SyntaxError,
+        "'return', 'break' and 'continue' are not valid inside an if or match expression",
+        Some(span),
+    )
+}
+
+// a return, break or continue that would leave the expression, labels
+// are the ones of the loops inside it and unlabeled jumps only stay
+// inside when they are nested in one of those loops
+fn leaving_jump(nodes: &[AstNodeType], labels: &[String], nested: bool) -> Option<Span> {
+    nodes.iter().find_map(|node| match node {
+        AstNodeType::ReturnStatement(node) => Some(node.span.clone()),
+        AstNodeType::BreakStatement(node) => jump_leaves(&node.label, &node.span, labels, nested),
+        AstNodeType::ContinueStatement(node) => {
+            jump_leaves(&node.label, &node.span, labels, nested)
+        }
+        AstNodeType::Block(node) => leaving_jump(&node.children, labels, nested),
+        AstNodeType::IfStatement(node) => if_leaving_jump(node, labels, nested),
+        AstNodeType::WhileStatement(node) => loop_leaving_jump(&node.body, &node.label, labels),
+        AstNodeType::ForStatement(node) => loop_leaving_jump(&node.body, &node.label, labels),
+        AstNodeType::Expression(Expression::MatchExpression(node)) => node
+            .arms
+            .iter()
+            .find_map(|arm| leaving_jump(&arm.body.children, labels, nested)),
+        _ => None,
+    })
+}
+
+fn if_leaving_jump(node: &IfStatement, labels: &[String], nested: bool) -> Option<Span> {
+    leaving_jump(&node.body.children, labels, nested).or_else(|| match &node.else_node {
+        Some(else_node) => match &else_node.body {
+            ElseBody::Block(block) => leaving_jump(&block.children, labels, nested),
+            ElseBody::If(node) => if_leaving_jump(node, labels, nested),
+        },
+        None => None,
+    })
+}
+
+fn loop_leaving_jump(body: &Block, label: &Option<Identifier>, labels: &[String]) -> Option<Span> {
+    let mut labels = labels.to_vec();
+    if let Some(label) = label {
+        labels.push(label.name.clone());
+    }
+    leaving_jump(&body.children, &labels, true)
+}
+
+fn jump_leaves(
+    label: &Option<Identifier>,
+    span: &Span,
+    labels: &[String],
+    nested: bool,
+) -> Option<Span> {
+    let stays = match label {
+        Some(label) => labels.contains(&label.name),
+        None => nested,
+    };
+    (!stays).then(|| span.clone())
+}
+
+// operators that can continue an expression
+fn is_binary_operator(token_type: &LexerTokenType) -> bool {
+    matches!(
+        token_type,
+        LexerTokenType::OrOperator
+            | LexerTokenType::AndOperator
+            | LexerTokenType::GreaterThanOperator
+            | LexerTokenType::LessThanOperator
+            | LexerTokenType::EqualityOperator
+            | LexerTokenType::NotEqualOperator
+            | LexerTokenType::GreaterThanOrEqualOperator
+            | LexerTokenType::LessThanOrEqualOperator
+            | LexerTokenType::AddOperator
+            | LexerTokenType::SubtractOperator
+            | LexerTokenType::MultiplyOperator
+            | LexerTokenType::DivideOperator
+            | LexerTokenType::IntDivideOperator
+            | LexerTokenType::ModuloOperator
+            | LexerTokenType::PowerOperator
+    )
+}
+
+// names bound anywhere in a pattern
+fn pattern_bindings<'a>(pattern: &'a Pattern, bindings: &mut Vec<&'a Identifier>) {
+    match pattern {
+        Pattern::Binding(identifier) => bindings.push(identifier),
+        Pattern::Variant(variant) => {
+            for pattern in &variant.patterns {
+                pattern_bindings(pattern, bindings);
+            }
         }
+        Pattern::Literal(_) | Pattern::Wildcard(_) => {}
     }
 }
@@ -0,0 +1,859 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::{
+    ast::{
+        assignament_statement::{AssignamentNode, VarType},
+        block::Block,
+        bool::Bool,
+        call_expression::CallExpression,
+        else_statement::ElseBody,
+        enum_declaration::EnumVariant,
+        for_statement::ForStatement,
+        identifier::Identifier,
+        if_statement::IfStatement,
+        match_expression::{MatchExpression, Pattern},
+        module::ModuleAst,
+        struct_expression::StructExpression,
+        AstNodeType, Expression, Type,
+    },
+    core::{
+        error::{EgoError, ErrorType},
+        span::Span,
+    },
+};
+
+// name shown for functions created by fn (...) {...}
+const ANONYMOUS_FUNCTION: &str = "<anonymous>";
+
+// what is known about a value before running the module
+#[derive(Debug, Clone)]
+enum StaticType {
+    Known(Type),
+    Vector,
+    Object,
+    Function(Signature),
+    // instance of the named struct
+    Instance(String),
+    Unknown,
+}
+
+impl StaticType {
+    // unknown values are trusted, they are checked at runtime
+    fn matches(&self, annotation: &Type) -> bool {
+        match self {
+            StaticType::Known(t) => t == annotation,
+            StaticType::Unknown => true,
+            _ => false,
+        }
+    }
+
+    // type of a value that can come from either side
+    fn join(self, other: StaticType) -> StaticType {
+        match (self, other) {
+            (StaticType::Known(a), StaticType::Known(b)) if a == b => StaticType::Known(a),
+            (StaticType::Vector, StaticType::Vector) => StaticType::Vector,
+            (StaticType::Object, StaticType::Object) => StaticType::Object,
+            (StaticType::Instance(a), StaticType::Instance(b)) if a == b => StaticType::Instance(a),
+            _ => StaticType::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for StaticType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StaticType::Known(t) => write!(f, "{t}"),
+            StaticType::Vector => write!(f, "vector"),
+            StaticType::Object => write!(f, "object"),
+            StaticType::Function(_) => write!(f, "function"),
+            StaticType::Instance(name) => write!(f, "{name}"),
+            StaticType::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Signature {
+    parameters: Vec<Identifier>,
+    returns: Box<StaticType>,
+}
+
+#[derive(Debug, Clone)]
+struct Variable {
+    value: StaticType,
+    annotation: Option<Type>,
+}
+
+// function whose body is being checked
+struct FunctionContext {
+    name: String,
+    return_type: Option<Type>,
+    returns: Vec<StaticType>,
+}
+
+// checks type annotations against the types flowing
+// through the module without running it
+pub struct Checker {
+    scopes: Vec<HashMap<String, Variable>>,
+    functions: Vec<FunctionContext>,
+    // fields of the declared structs
+    structs: HashMap<String, Vec<Identifier>>,
+    // variants of the declared enums
+    enums: HashMap<String, Vec<EnumVariant>>,
+    // identifiers assigned after their declaration, their
+    // inferred type can change so it's not trusted
+    reassigned: HashSet<String>,
+    errors: Vec<EgoError>,
+}
+
+impl Checker {
+    pub fn check(ast: &ModuleAst) -> Vec<EgoError> {
+        let mut reassigned = HashSet::new();
+        collect_reassigned(&ast.children, &mut reassigned);
+
+        let mut checker = Checker {
+            scopes: vec![HashMap::new()],
+            functions: vec![],
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            reassigned,
+            errors: vec![],
+        };
+        checker.check_nodes(&ast.children);
+        checker.errors
+    }
+
+    fn error(&mut self, message: String, span: &Span) {
+        self.errors.push(EgoError::new(
+            ErrorType::TypeError,
+            message.as_str(),
+            Some(span.clone()),
+        ));
+    }
+
+    fn declare(&mut self, identifier: &Identifier, value: StaticType) {
+        let value = match identifier.annotation {
+            Some(annotation) => StaticType::Known(annotation),
+            None if self.reassigned.contains(&identifier.name) => StaticType::Unknown,
+            None => value,
+        };
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(
+                identifier.name.clone(),
+                Variable {
+                    value,
+                    annotation: identifier.annotation,
+                },
+            );
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Variable> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    // checks the nodes in a new scope and returns the
+    // type of the value the block evaluates to
+    fn check_block(&mut self, block: &Block) -> StaticType {
+        self.scopes.push(HashMap::new());
+        let value = self.check_nodes(&block.children);
+        self.scopes.pop();
+        value
+    }
+
+    fn check_nodes(&mut self, nodes: &[AstNodeType]) -> StaticType {
+        // hoisting
+        for node in nodes {
+            match node {
+                AstNodeType::FunctionDeclaration(function) => {
+                    let signature = Signature {
+                        parameters: function.parameters.clone(),
+                        returns: Box::new(returns_annotation(function.return_type)),
+                    };
+                    self.declare(&function.identifier, StaticType::Function(signature));
+                }
+                AstNodeType::StructDeclaration(node) => {
+                    self.structs
+                        .insert(node.identifier.name.clone(), node.fields.clone());
+                    self.declare(&node.identifier, StaticType::Unknown);
+                }
+                AstNodeType::EnumDeclaration(node) => {
+                    self.enums
+                        .insert(node.identifier.name.clone(), node.variants.clone());
+                    self.declare(&node.identifier, StaticType::Unknown);
+                }
+                _ => {}
+            }
+        }
+
+        let mut value = StaticType::Known(Type::Nothing);
+        for node in nodes {
+            value = match node {
+                AstNodeType::Expression(expr) => self.check_expression(expr),
+                AstNodeType::IfStatement(node) => self.check_if(node),
+                node => {
+                    self.check_node(node);
+                    StaticType::Known(Type::Nothing)
+                }
+            };
+        }
+        value
+    }
+
+    fn check_node(&mut self, node: &AstNodeType) {
+        match node {
+            AstNodeType::Block(node) => {
+                self.check_block(node);
+            }
+            AstNodeType::FunctionDeclaration(node) => {
+                let name = node.identifier.name.as_str();
+                let returns = self.check_function(
+                    name,
+                    &node.parameters,
+                    node.return_type,
+                    &node.body,
+                    &node.span,
+                );
+                // calls after the declaration get the inferred return type
+                if node.return_type.is_none() {
+                    let signature = Signature {
+                        parameters: node.parameters.clone(),
+                        returns: Box::new(returns),
+                    };
+                    self.declare(&node.identifier, StaticType::Function(signature));
+                }
+            }
+            AstNodeType::ImplDeclaration(node) => {
+                for method in &node.methods {
+                    self.check_function(
+                        &method.identifier.name,
+                        &method.parameters,
+                        method.return_type,
+                        &method.body,
+                        &method.span,
+                    );
+                }
+            }
+            AstNodeType::IfStatement(node) => {
+                self.check_if(node);
+            }
+            AstNodeType::WhileStatement(node) => {
+                self.check_expression(&node.condition);
+                self.check_block(&node.body);
+            }
+            AstNodeType::ForStatement(node) => self.check_for(node),
+            AstNodeType::AssignamentStatement(node) => self.check_assignament(node),
+            AstNodeType::MemberAssignament(node) => {
+                self.check_expression(&node.target);
+                self.check_expression(&node.init);
+            }
+            AstNodeType::ReturnStatement(node) => {
+                let value = self.check_expression(&node.value);
+                // module level returns are ignored
+                let function = match self.functions.last_mut() {
+                    Some(function) => function,
+                    None => return,
+                };
+                function.returns.push(value.clone());
+                if let Some(return_type) = function.return_type {
+                    if !value.matches(&return_type) {
+                        let message = format!(
+                            "Function '{}' must return a value of type '{return_type}' but returned '{value}'",
+                            function.name
+                        );
+                        self.error(message, &node.span);
+                    }
+                }
+            }
+            AstNodeType::Expression(expr) => {
+                self.check_expression(expr);
+            }
+            _ => {}
+        }
+    }
+
+    // returns the type of the values returned by the function
+    fn check_function(
+        &mut self,
+        name: &str,
+        parameters: &[Identifier],
+        return_type: Option<Type>,
+        body: &Block,
+        span: &Span,
+    ) -> StaticType {
+        self.scopes.push(HashMap::new());
+        for parameter in parameters {
+            self.declare(parameter, StaticType::Unknown);
+        }
+        self.functions.push(FunctionContext {
+            name: name.to_string(),
+            return_type,
+            returns: vec![],
+        });
+        self.check_block(body);
+        let function = self.functions.pop();
+        self.scopes.pop();
+
+        let mut returns = function.map(|f| f.returns).unwrap_or_default();
+        if !always_returns(body) {
+            // falling off the end returns nothing
+            if let Some(return_type) = return_type.filter(|t| *t != Type::Nothing) {
+                self.error(
+                    format!("Function '{name}' must return a value of type '{return_type}' but can end without returning"),
+                    span,
+                );
+            }
+            returns.push(StaticType::Known(Type::Nothing));
+        }
+
+        match return_type {
+            Some(return_type) => StaticType::Known(return_type),
+            None => returns
+                .into_iter()
+                .reduce(StaticType::join)
+                .unwrap_or(StaticType::Known(Type::Nothing)),
+        }
+    }
+
+    fn check_if(&mut self, node: &IfStatement) -> StaticType {
+        self.check_expression(&node.condition);
+        let value = self.check_block(&node.body);
+        let otherwise = match &node.else_node {
+            Some(else_node) => match &else_node.body {
+                ElseBody::Block(block) => self.check_block(block),
+                ElseBody::If(node) => self.check_if(node),
+            },
+            None => StaticType::Known(Type::Nothing),
+        };
+        value.join(otherwise)
+    }
+
+    fn check_for(&mut self, node: &ForStatement) {
+        let iterable = self.check_expression(&node.iterable);
+        let item = match (&node.iterable, iterable) {
+            (Expression::RangeExpression(_), _) => StaticType::Known(Type::Number),
+            (_, StaticType::Known(Type::String)) => StaticType::Known(Type::String),
+            _ => StaticType::Unknown,
+        };
+
+        self.scopes.push(HashMap::new());
+        self.declare(&node.item, item);
+        self.check_block(&node.body);
+        self.scopes.pop();
+    }
+
+    fn check_assignament(&mut self, node: &AssignamentNode) {
+        let value = self.check_expression(&node.init);
+        let annotation = match node.var_type {
+            VarType::Let | VarType::Const => node.identifier.annotation,
+            VarType::None => self
+                .lookup(&node.identifier.name)
+                .and_then(|variable| variable.annotation),
+        };
+        if let Some(annotation) = annotation {
+            if !value.matches(&annotation) {
+                self.error(
+                    format!(
+                        "Annotation of type '{annotation}' differs from assigned '{value}' value"
+                    ),
+                    node.init.span(),
+                );
+            }
+        }
+
+        if !matches!(node.var_type, VarType::None) {
+            self.declare(&node.identifier, value);
+        }
+    }
+
+    fn check_call(&mut self, node: &CallExpression) -> StaticType {
+        let callee = self.check_expression(&node.callee);
+        let arguments: Vec<(StaticType, Span)> = node
+            .arguments
+            .children
+            .iter()
+            .map(|argument| match argument {
+                Some(argument) => (self.check_expression(argument), argument.span().clone()),
+                None => (StaticType::Known(Type::Nothing), node.span.clone()),
+            })
+            .collect();
+
+        // State.Running(3)
+        if let Expression::MemberExpression(member) = node.callee.as_ref() {
+            if let Expression::Identifier(enumeration) = member.object.as_ref() {
+                if self.enums.contains_key(&enumeration.name) {
+                    let name = enumeration.name.clone();
+                    self.check_variant(&name, &member.property, &arguments, &node.span);
+                    return StaticType::Unknown;
+                }
+            }
+        }
+
+        let signature = match callee {
+            StaticType::Function(signature) => signature,
+            _ => return StaticType::Unknown,
+        };
+        let name = node.callee_name().unwrap_or(ANONYMOUS_FUNCTION);
+        for (index, parameter) in signature.parameters.iter().enumerate() {
+            let annotation = match parameter.annotation {
+                Some(annotation) => annotation,
+                None => continue,
+            };
+            // missing arguments are nothing
+            let (value, span) = arguments
+                .get(index)
+                .cloned()
+                .unwrap_or((StaticType::Known(Type::Nothing), node.span.clone()));
+            if !value.matches(&annotation) {
+                self.error(
+                    format!(
+                        "Parameter '{}' of '{name}' expects a value of type '{annotation}' but got '{value}'",
+                        parameter.name
+                    ),
+                    &span,
+                );
+            }
+        }
+        *signature.returns
+    }
+
+    fn check_struct(&mut self, node: &StructExpression) -> StaticType {
+        let values: Vec<(&Identifier, StaticType, &Span)> = node
+            .fields
+            .properties
+            .iter()
+            .map(|(key, value)| (key, self.check_expression(value), value.span()))
+            .collect();
+
+        let name = &node.identifier.name;
+        let fields = match self.structs.get(name) {
+            Some(fields) => fields.clone(),
+            None => return StaticType::Unknown,
+        };
+        for (key, _, _) in &values {
+            if !fields.iter().any(|field| field.name == key.name) {
+                let message = format!(
+                    "Struct '{name}' has no field or method named '{}'",
+                    key.name
+                );
+                self.error(message, &key.span);
+            }
+        }
+        for field in &fields {
+            let annotation = match field.annotation {
+                Some(annotation) => annotation,
+                None => continue,
+            };
+            // missing fields are nothing
+            let (value, span) = values
+                .iter()
+                .find(|(key, _, _)| key.name == field.name)
+                .map(|(_, value, span)| (value.clone(), *span))
+                .unwrap_or((StaticType::Known(Type::Nothing), &node.span));
+            if !value.matches(&annotation) {
+                let message = format!(
+                    "Field '{}' of '{name}' expects a value of type '{annotation}' but got '{value}'",
+                    field.name
+                );
+                self.error(message, span);
+            }
+        }
+        StaticType::Instance(name.clone())
+    }
+
+    fn check_variant(
+        &mut self,
+        enumeration: &str,
+        variant: &Identifier,
+        arguments: &[(StaticType, Span)],
+        span: &Span,
+    ) {
+        let fields = match self.enum_variant(enumeration, variant) {
+            Some(variant) => variant.fields,
+            None => return,
+        };
+        let name = format!("{enumeration}.{}", variant.name);
+        if arguments.len() != fields.len() {
+            let message = format!(
+                "Variant '{name}' expects {} but got {}",
+                plural(fields.len(), "value"),
+                arguments.len()
+            );
+            self.error(message, span);
+            return;
+        }
+
+        for (field, (value, span)) in fields.iter().zip(arguments) {
+            let annotation = match field.annotation {
+                Some(annotation) => annotation,
+                None => continue,
+            };
+            if !value.matches(&annotation) {
+                let message = format!(
+                    "Field '{}' of '{name}' expects a value of type '{annotation}' but got '{value}'",
+                    field.name
+                );
+                self.error(message, span);
+            }
+        }
+    }
+
+    // reports variants that are not declared in a known enum
+    fn enum_variant(&mut self, enumeration: &str, variant: &Identifier) -> Option<EnumVariant> {
+        let found = self
+            .enums
+            .get(enumeration)?
+            .iter()
+            .find(|declared| declared.identifier.name == variant.name)
+            .cloned();
+        if found.is_none() {
+            self.error(
+                format!(
+                    "Enum '{enumeration}' has no variant named '{}'",
+                    variant.name
+                ),
+                &variant.span,
+            );
+        }
+        found
+    }
+
+    // the value of a match is the value of any of its arms
+    fn check_match(&mut self, node: &MatchExpression) -> StaticType {
+        self.check_expression(&node.subject);
+
+        let mut value: Option<StaticType> = None;
+        for arm in &node.arms {
+            self.scopes.push(HashMap::new());
+            self.check_pattern(&arm.pattern);
+            let arm_value = self.check_block(&arm.body);
+            self.scopes.pop();
+            value = Some(match value {
+                Some(value) => value.join(arm_value),
+                None => arm_value,
+            });
+        }
+        value.unwrap_or(StaticType::Unknown)
+    }
+
+    // declares the names bound by the pattern
+    fn check_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Binding(identifier) => self.declare(identifier, StaticType::Unknown),
+            Pattern::Variant(pattern) => {
+                let name = &pattern.enumeration.name;
+                if let Some(variant) = self.enum_variant(name, &pattern.variant) {
+                    // State.Running matches any payload
+                    let patterns = pattern.patterns.len();
+                    if patterns > 0 && patterns != variant.fields.len() {
+                        let message = format!(
+                            "Variant '{name}.{}' has {} but the pattern matches {}",
+                            variant.identifier.name,
+                            plural(variant.fields.len(), "field"),
+                            patterns
+                        );
+                        self.error(message, &pattern.span);
+                    }
+                }
+                for pattern in &pattern.patterns {
+                    self.check_pattern(pattern);
+                }
+            }
+            Pattern::Literal(_) | Pattern::Wildcard(_) => {}
+        }
+    }
+
+    fn check_expression(&mut self, node: &Expression) -> StaticType {
+        match node {
+            Expression::Bool(_) => StaticType::Known(Type::Bool),
+            Expression::Number(_) => StaticType::Known(Type::Number),
+            Expression::StringLiteral(_) => StaticType::Known(Type::String),
+            Expression::Nothing(_) => StaticType::Known(Type::Nothing),
+            Expression::TemplateString(v) => {
+                for part in &v.parts {
+                    self.check_expression(part);
+                }
+                StaticType::Known(Type::String)
+            }
+            Expression::Identifier(v) => self
+                .lookup(&v.name)
+                .map(|variable| variable.value.clone())
+                .unwrap_or(StaticType::Unknown),
+            Expression::Vector(v) => {
+                for child in v.children.iter().flatten() {
+                    self.check_expression(child);
+                }
+                StaticType::Vector
+            }
+            Expression::Object(v) => {
+                for (_, value) in &v.properties {
+                    self.check_expression(value);
+                }
+                StaticType::Object
+            }
+            Expression::RangeExpression(v) => {
+                self.check_expression(&v.start);
+                self.check_expression(&v.end);
+                StaticType::Vector
+            }
+            Expression::IndexExpression(v) => {
+                self.check_expression(&v.object);
+                self.check_expression(&v.index);
+                StaticType::Unknown
+            }
+            Expression::MemberExpression(v) => {
+                self.check_expression(&v.object);
+                StaticType::Unknown
+            }
+            Expression::IfExpression(v) => self.check_if(v),
+            Expression::MatchExpression(v) => self.check_match(v),
+            Expression::StructExpression(v) => self.check_struct(v),
+            Expression::FunctionExpression(v) => {
+                let returns = self.check_function(
+                    ANONYMOUS_FUNCTION,
+                    &v.parameters,
+                    v.return_type,
+                    &v.body,
+                    &v.span,
+                );
+                StaticType::Function(Signature {
+                    parameters: v.parameters.clone(),
+                    returns: Box::new(returns),
+                })
+            }
+            Expression::CallExpression(v) => self.check_call(v),
+            Expression::UnaryExpression(v) => {
+                let operand = self.check_expression(&v.operand);
+                match v.operator.as_str() {
+                    "!" => StaticType::Known(Type::Bool),
+                    _ => {
+                        if !operand.matches(&Type::Number) {
+                            self.error(
+                                format!(
+                                    "Cannot apply '{}' to a value of type '{operand}'",
+                                    v.operator
+                                ),
+                                &v.span,
+                            );
+                        }
+                        StaticType::Known(Type::Number)
+                    }
+                }
+            }
+            Expression::BinaryExpression(v) => {
+                let left = self.check_expression(&v.left);
+                let right = self.check_expression(&v.right);
+                binary_result(v.operator.as_str(), left, right)
+            }
+        }
+    }
+}
+
+// 1 value | 2 values
+fn plural(count: usize, noun: &str) -> String {
+    match count {
+        1 => format!("1 {noun}"),
+        _ => format!("{count} {noun}s"),
+    }
+}
+
+fn returns_annotation(return_type: Option<Type>) -> StaticType {
+    match return_type {
+        Some(return_type) => StaticType::Known(return_type),
+        None => StaticType::Unknown,
+    }
+}
+
+// only the combinations that always produce the same
+// type are inferred, the rest are left unknown
+fn binary_result(operator: &str, left: StaticType, right: StaticType) -> StaticType {
+    use StaticType::Known;
+
+    match (operator, left, right) {
+        ("&&" | "||", _, _) => Known(Type::Bool),
+        (
+            "==" | "!=" | "<" | ">" | "<=" | ">=",
+            Known(Type::Number | Type::String | Type::Bool),
+            _,
+        ) => Known(Type::Bool),
+        ("+", Known(Type::String), Known(Type::String | Type::Number | Type::Bool))
+        | ("+", Known(Type::Number), Known(Type::String)) => Known(Type::String),
+        ("+" | "-" | "*" | "/" | "~/" | "%" | "**", Known(Type::Number), Known(Type::Number)) => {
+            Known(Type::Number)
+        }
+        _ => StaticType::Unknown,
+    }
+}
+
+// whether every path through the block reaches a return
+fn always_returns(block: &Block) -> bool {
+    block.children.iter().any(|node| match node {
+        AstNodeType::ReturnStatement(_) => true,
+        AstNodeType::IfStatement(node) => if_always_returns(node),
+        // while true only ends through a break
+        AstNodeType::WhileStatement(node) => {
+            matches!(node.condition, Expression::Bool(Bool { value: true, .. }))
+                && !breaks_out(&node.body.children, node.label.as_ref(), false)
+        }
+        // a value that no arm matches is an error
+        AstNodeType::Expression(Expression::MatchExpression(node)) => {
+            !node.arms.is_empty() && node.arms.iter().all(|arm| always_returns(&arm.body))
+        }
+        _ => false,
+    })
+}
+
+fn if_always_returns(node: &IfStatement) -> bool {
+    let otherwise = match &node.else_node {
+        Some(else_node) => match &else_node.body {
+            ElseBody::Block(block) => always_returns(block),
+            ElseBody::If(node) => if_always_returns(node),
+        },
+        None => false,
+    };
+    always_returns(&node.body) && otherwise
+}
+
+// a break leaving the loop with the given label, unlabeled
+// breaks only count outside of inner loops
+fn breaks_out(nodes: &[AstNodeType], label: Option<&Identifier>, nested: bool) -> bool {
+    nodes.iter().any(|node| match node {
+        AstNodeType::BreakStatement(node) => match (&node.label, label) {
+            (None, _) => !nested,
+            (Some(target), Some(label)) => target.name == label.name,
+            (Some(_), None) => false,
+        },
+        AstNodeType::Block(node) => breaks_out(&node.children, label, nested),
+        AstNodeType::IfStatement(node) => if_breaks_out(node, label, nested),
+        AstNodeType::WhileStatement(node) => breaks_out(&node.body.children, label, true),
+        AstNodeType::ForStatement(node) => breaks_out(&node.body.children, label, true),
+        AstNodeType::Expression(Expression::MatchExpression(node)) => node
+            .arms
+            .iter()
+            .any(|arm| breaks_out(&arm.body.children, label, nested)),
+        _ => false,
+    })
+}
+
+fn if_breaks_out(node: &IfStatement, label: Option<&Identifier>, nested: bool) -> bool {
+    let otherwise = match &node.else_node {
+        Some(else_node) => match &else_node.body {
+            ElseBody::Block(block) => breaks_out(&block.children, label, nested),
+            ElseBody::If(node) => if_breaks_out(node, label, nested),
+        },
+        None => false,
+    };
+    breaks_out(&node.body.children, label, nested) || otherwise
+}
+
+fn collect_reassigned(nodes: &[AstNodeType], names: &mut HashSet<String>) {
+    for node in nodes {
+        match node {
+            AstNodeType::AssignamentStatement(node) => {
+                if let VarType::None = node.var_type {
+                    names.insert(node.identifier.name.clone());
+                }
+                collect_reassigned_in(&node.init, names);
+            }
+            AstNodeType::Block(node) => collect_reassigned(&node.children, names),
+            AstNodeType::FunctionDeclaration(node) => {
+                collect_reassigned(&node.body.children, names)
+            }
+            AstNodeType::ImplDeclaration(node) => {
+                for method in &node.methods {
+                    collect_reassigned(&method.body.children, names);
+                }
+            }
+            AstNodeType::IfStatement(node) => collect_reassigned_in_if(node, names),
+            AstNodeType::WhileStatement(node) => {
+                collect_reassigned_in(&node.condition, names);
+                collect_reassigned(&node.body.children, names);
+            }
+            AstNodeType::ForStatement(node) => {
+                collect_reassigned_in(&node.iterable, names);
+                collect_reassigned(&node.body.children, names);
+            }
+            AstNodeType::MemberAssignament(node) => {
+                collect_reassigned_in(&node.target, names);
+                collect_reassigned_in(&node.init, names);
+            }
+            AstNodeType::ReturnStatement(node) => collect_reassigned_in(&node.value, names),
+            AstNodeType::Expression(expr) => collect_reassigned_in(expr, names),
+            _ => {}
+        }
+    }
+}
+
+fn collect_reassigned_in_if(node: &IfStatement, names: &mut HashSet<String>) {
+    collect_reassigned_in(&node.condition, names);
+    collect_reassigned(&node.body.children, names);
+    if let Some(else_node) = &node.else_node {
+        match &else_node.body {
+            ElseBody::Block(block) => collect_reassigned(&block.children, names),
+            ElseBody::If(node) => collect_reassigned_in_if(node, names),
+        }
+    }
+}
+
+// assignments only happen inside the blocks of
+// function, if and match expressions
+fn collect_reassigned_in(expr: &Expression, names: &mut HashSet<String>) {
+    match expr {
+        Expression::FunctionExpression(v) => collect_reassigned(&v.body.children, names),
+        Expression::IfExpression(v) => collect_reassigned_in_if(v, names),
+        Expression::MatchExpression(v) => {
+            collect_reassigned_in(&v.subject, names);
+            for arm in &v.arms {
+                collect_reassigned(&arm.body.children, names);
+            }
+        }
+        Expression::TemplateString(v) => {
+            for part in &v.parts {
+                collect_reassigned_in(part, names);
+            }
+        }
+        Expression::Vector(v) => {
+            for child in v.children.iter().flatten() {
+                collect_reassigned_in(child, names);
+            }
+        }
+        Expression::Object(v) => {
+            for (_, value) in &v.properties {
+                collect_reassigned_in(value, names);
+            }
+        }
+        Expression::StructExpression(v) => {
+            for (_, value) in &v.fields.properties {
+                collect_reassigned_in(value, names);
+            }
+        }
+        Expression::CallExpression(v) => {
+            collect_reassigned_in(&v.callee, names);
+            for argument in v.arguments.children.iter().flatten() {
+                collect_reassigned_in(argument, names);
+            }
+        }
+        Expression::BinaryExpression(v) => {
+            collect_reassigned_in(&v.left, names);
+            collect_reassigned_in(&v.right, names);
+        }
+        Expression::UnaryExpression(v) => collect_reassigned_in(&v.operand, names),
+        Expression::IndexExpression(v) => {
+            collect_reassigned_in(&v.object, names);
+            collect_reassigned_in(&v.index, names);
+        }
+        Expression::MemberExpression(v) => collect_reassigned_in(&v.object, names),
+        Expression::RangeExpression(v) => {
+            collect_reassigned_in(&v.start, names);
+            collect_reassigned_in(&v.end, names);
+        }
+        Expression::StringLiteral(_)
+        | Expression::Number(_)
+        | Expression::Bool(_)
+        | Expression::Identifier(_)
+        | Expression::Nothing(_) => {}
+    }
+}
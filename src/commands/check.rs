@@ -0,0 +1,48 @@
+use std::fs;
+
+use crate::ast::lex;
+use crate::ast::Module;
+use crate::checker::Checker;
+use crate::core::error::{EgoError, ErrorType};
+
+pub struct Check {
+    args: Vec<String>,
+}
+
+impl Check {
+    pub fn new(args: Vec<String>) -> Check {
+        Check { args }
+    }
+    pub fn exec(&self) {
+        let module_name = if !self.args.is_empty() {
+            self.args[0].clone()
+        } else {
+            "main.ego".to_string() // default lookup on a ego project
+        };
+
+        let file_content = fs::read_to_string(&module_name).unwrap_or_else(|_| {
+            EgoError::new(
+                ErrorType::FatalError,
+                format!("Cannot read {}\n", module_name).as_str(),
+                None,
+            )
+            .throw()
+        });
+
+        let tokens = lex(file_content, &module_name).unwrap_or_else(|e| e.throw());
+        let mut module = Module::new(module_name.clone(), tokens);
+        let (ast, mut errors) = module.parse_recovering();
+        // type errors are only looked for in modules that parse
+        if errors.is_empty() {
+            errors = Checker::check(&ast);
+        }
+        if let Some((last, errors)) = errors.split_last() {
+            for e in errors {
+                e.report();
+            }
+            last.throw();
+        }
+
+        println!(" ⚈ No type errors found in {module_name}");
+    }
+}
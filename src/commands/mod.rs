@@ -1,17 +1,19 @@
+pub mod check;
 pub mod logo;
 pub mod new;
 pub mod run;
 
+use self::check::Check;
 use self::logo::Logo;
 use self::new::New;
 use self::run::Run;
 
-use crate::core::error;
-use crate::core::error::ErrorType;
+use crate::core::error::{EgoError, ErrorType};
 use std::env;
 
 pub enum Command {
     Run(Run),
+    Check(Check),
     Logo(Logo),
     New(New),
 }
@@ -25,32 +27,32 @@ impl Command {
             return Command::cmd_from_str(command.as_str(), remaining_args.to_vec());
         } else {
             // print help message instead of error
-            error::throw(
+            EgoError::new(
                 ErrorType::EgoUsageError,
                 "a command is required to execute ego",
                 None,
-            );
-            std::process::exit(1); // to avoid types error
+            )
+            .throw();
         };
     }
     fn cmd_from_str(command: &str, args: Vec<String>) -> Command {
         match command {
             "run" => Command::Run(Run::new(args)),
+            "check" => Command::Check(Check::new(args)),
             "logo" => Command::Logo(Logo::new(args)),
             "new" => Command::New(New::new(args)),
-            _ => {
-                error::throw(
-                    ErrorType::EgoUsageError,
-                    format!("Unknown command {}", command).as_str(),
-                    None,
-                );
-                std::process::exit(1);
-            }
+            _ => EgoError::new(
+                ErrorType::EgoUsageError,
+                format!("Unknown command {}", command).as_str(),
+                None,
+            )
+            .throw(),
         }
     }
     pub fn exec(&self) {
         match self {
             Command::Run(v) => v.exec(),
+            Command::Check(v) => v.exec(),
             Command::Logo(v) => v.exec(),
             Command::New(v) => v.exec(),
         }
@@ -3,8 +3,7 @@ use std::fs;
 use crate::ast::lex;
 use crate::ast::Module;
 use crate::compiler::Compiler;
-use crate::core::error;
-use crate::core::error::ErrorType;
+use crate::core::error::{EgoError, ErrorType};
 use crate::runtime::Interpreter;
 
 pub struct Run {
@@ -26,15 +25,15 @@ impl Run {
         };
 
         let file_content = fs::read_to_string(&module_name).unwrap_or_else(|_| {
-            error::throw(
+            EgoError::new(
                 ErrorType::FatalError,
                 format!("Cannot read {}\n", module_name).as_str(),
                 None,
-            );
-            std::process::exit(1); // to avoid types error
+            )
+            .throw()
         });
 
-        let tokens = lex(file_content);
+        let tokens = lex(file_content, &module_name).unwrap_or_else(|e| e.throw());
         if self.debug() {
             println!("\nLexer tokens: \n-------------");
             for (i, token) in tokens.iter().enumerate() {
@@ -43,18 +42,25 @@ impl Run {
         }
 
         let mut module = Module::new(module_name, tokens);
-        let ast = module.parse();
+        let (ast, errors) = module.parse_recovering();
+        if let Some((last, errors)) = errors.split_last() {
+            // every syntax error is shown before exiting
+            for e in errors {
+                e.report();
+            }
+            last.throw();
+        }
         if self.debug() {
             println!("\nAst nodes: \n---------------\n{:#?}", ast);
         }
 
         if self.args.contains(&"-vm".to_string()) {
-            let bytecode = Compiler::gen_bytecode(ast);
+            let bytecode = Compiler::gen_bytecode(ast).unwrap_or_else(|e| e.throw());
             let mut vm = self_vm::vm::Vm::new(bytecode);
             vm.run();
         } else {
             let mut interpreter = Interpreter::new(ast.clone());
-            interpreter.exec(self.debug());
+            interpreter.exec(self.debug()).unwrap_or_else(|e| e.throw());
         }
     }
 }
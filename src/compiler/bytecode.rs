@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::core::error::{self, ErrorType};
+use crate::core::error::{EgoError, ErrorType};
 
 pub struct Bytecode {
     table: HashMap<String, u8>,
@@ -21,17 +21,16 @@ impl Bytecode {
     }
 }
 
-pub fn get_bytecode(item: String) -> u8 {
+pub fn get_bytecode(item: String) -> Result<u8, EgoError> {
     let mut bytecode_handler = Bytecode::get_handler();
 
     if let Some(bytecode) = bytecode_handler.get_bytecode_representation(item) {
-        bytecode
+        Ok(bytecode)
     } else {
-        error::throw(
+        Err(EgoError::new(
             ErrorType::CompilationError,
             "Member name not recognized",
             None,
-        );
-        std::process::exit(1)
+        ))
     }
 }
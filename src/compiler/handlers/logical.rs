@@ -0,0 +1,101 @@
+use crate::{
+    ast::{
+        binary_expression::BinaryExpression, block::Block, else_statement::ElseBody,
+        if_statement::IfStatement, AstNodeType, Expression,
+    },
+    core::error::{EgoError, ErrorType},
+};
+
+pub fn is_logical(operator: &str) -> bool {
+    operator == "&&" || operator == "||"
+}
+
+// the self vm has no jumps to skip the right side, so
+// '&&' and '||' can't keep their short-circuiting there.
+// The whole module is checked before compiling it, so
+// none of them is silently dropped
+pub fn reject_logical(nodes: &[AstNodeType]) -> Result<(), EgoError> {
+    match nodes.iter().find_map(logical_in_node) {
+        Some(node) => Err(EgoError::new(
+            ErrorType::CompilationError,
+            format!(
+                "Logical '{}' is not supported by the self vm yet",
+                node.operator
+            )
+            .as_str(),
+            Some(node.span.clone()),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn logical_in_node(node: &AstNodeType) -> Option<&BinaryExpression> {
+    match node {
+        AstNodeType::Expression(expr) => logical_in(expr),
+        AstNodeType::AssignamentStatement(node) => logical_in(&node.init),
+        AstNodeType::MemberAssignament(node) => {
+            logical_in(&node.target).or_else(|| logical_in(&node.init))
+        }
+        AstNodeType::ReturnStatement(node) => logical_in(&node.value),
+        AstNodeType::Block(node) => logical_in_block(node),
+        AstNodeType::IfStatement(node) => logical_in_if(node),
+        AstNodeType::WhileStatement(node) => {
+            logical_in(&node.condition).or_else(|| logical_in_block(&node.body))
+        }
+        AstNodeType::ForStatement(node) => {
+            logical_in(&node.iterable).or_else(|| logical_in_block(&node.body))
+        }
+        AstNodeType::FunctionDeclaration(node) => logical_in_block(&node.body),
+        AstNodeType::ImplDeclaration(node) => node
+            .methods
+            .iter()
+            .find_map(|method| logical_in_block(&method.body)),
+        _ => None,
+    }
+}
+
+fn logical_in_block(block: &Block) -> Option<&BinaryExpression> {
+    block.children.iter().find_map(logical_in_node)
+}
+
+fn logical_in_if(node: &IfStatement) -> Option<&BinaryExpression> {
+    logical_in(&node.condition)
+        .or_else(|| logical_in_block(&node.body))
+        .or_else(
+            || match node.else_node.as_ref().map(|else_node| &else_node.body) {
+                Some(ElseBody::Block(block)) => logical_in_block(block),
+                Some(ElseBody::If(node)) => logical_in_if(node),
+                None => None,
+            },
+        )
+}
+
+fn logical_in(expr: &Expression) -> Option<&BinaryExpression> {
+    match expr {
+        Expression::BinaryExpression(v) if is_logical(&v.operator) => Some(v),
+        Expression::BinaryExpression(v) => logical_in(&v.left).or_else(|| logical_in(&v.right)),
+        Expression::UnaryExpression(v) => logical_in(&v.operand),
+        Expression::CallExpression(v) => logical_in(&v.callee)
+            .or_else(|| v.arguments.children.iter().flatten().find_map(logical_in)),
+        Expression::IndexExpression(v) => logical_in(&v.object).or_else(|| logical_in(&v.index)),
+        Expression::MemberExpression(v) => logical_in(&v.object),
+        Expression::RangeExpression(v) => logical_in(&v.start).or_else(|| logical_in(&v.end)),
+        Expression::TemplateString(v) => v.parts.iter().find_map(logical_in),
+        Expression::Vector(v) => v.children.iter().flatten().find_map(logical_in),
+        Expression::Object(v) => v.properties.iter().find_map(|(_, value)| logical_in(value)),
+        Expression::StructExpression(v) => v
+            .fields
+            .properties
+            .iter()
+            .find_map(|(_, value)| logical_in(value)),
+        Expression::FunctionExpression(v) => logical_in_block(&v.body),
+        Expression::IfExpression(v) => logical_in_if(v),
+        Expression::MatchExpression(v) => logical_in(&v.subject)
+            .or_else(|| v.arms.iter().find_map(|arm| logical_in_block(&arm.body))),
+        Expression::StringLiteral(_)
+        | Expression::Number(_)
+        | Expression::Bool(_)
+        | Expression::Identifier(_)
+        | Expression::Nothing(_) => None,
+    }
+}
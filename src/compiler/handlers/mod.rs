@@ -1,3 +1,5 @@
+mod logical;
 mod print;
 
+pub use logical::*;
 pub use print::*;
@@ -1,6 +1,7 @@
 use crate::{
     ast::{call_expression::CallExpression, Expression},
     compiler::bytecode::get_bytecode,
+    core::error::{EgoError, ErrorType},
 };
 
 use self_vm::utils::{
@@ -8,35 +9,51 @@ use self_vm::utils::{
     Number,
 };
 
-pub fn print_as_bytecode(node: &CallExpression) -> Vec<u8> {
+pub fn print_as_bytecode(node: &CallExpression) -> Result<Vec<u8>, EgoError> {
     let mut bytecode = vec![];
 
     // load arguments
-    let load_const_bytecode = get_bytecode("load_const".to_string());
+    let load_const_bytecode = get_bytecode("load_const".to_string())?;
     for argument in &node.arguments.children {
         if let Some(arg) = argument {
             match arg {
                 Expression::Number(v) => {
                     bytecode.push(load_const_bytecode);
-                    if v.value.is_sign_negative() {
-                        panic!("Cannot compile negative numbers on self");
-                    }
 
                     // value type bytecode
                     let num = match i64::try_from(v.value as i64) {
                         Ok(int_num) => int_num,
-                        Err(_) => panic!("Conversion failed: out of range"),
+                        Err(_) => {
+                            return Err(EgoError::new(
+                                ErrorType::CompilationError,
+                                "Conversion failed: out of range",
+                                Some(v.span.clone()),
+                            ))
+                        }
                     };
 
-                    let num_type_bytecode = get_bytecode("i64".to_string());
+                    let num_type_bytecode = get_bytecode("i64".to_string())?;
                     bytecode.push(num_type_bytecode);
 
                     // value bytecode
                     let num_bytecode = bytes_from_64(Number::I64(num));
                     bytecode.extend_from_slice(&num_bytecode);
                 }
+                Expression::UnaryExpression(v) => {
+                    return Err(EgoError::new(
+                        ErrorType::CompilationError,
+                        format!("Unary '{}' is not supported by the self vm yet", v.operator)
+                            .as_str(),
+                        Some(v.span.clone()),
+                    ))
+                }
                 _ => {
-                    println!("- Argument skipped")
+                    // skipping it would print the wrong number of arguments
+                    return Err(EgoError::new(
+                        ErrorType::CompilationError,
+                        "Only number literals can be printed by the self vm yet",
+                        Some(arg.span().clone()),
+                    ));
                 }
             }
         } else {
@@ -45,7 +62,7 @@ pub fn print_as_bytecode(node: &CallExpression) -> Vec<u8> {
     }
 
     // print instruction bytecode
-    let print_bytecode = get_bytecode(node.identifier.name.to_string());
+    let print_bytecode = get_bytecode("print".to_string())?;
     bytecode.push(print_bytecode);
 
     // number of args bytecode
@@ -53,5 +70,5 @@ pub fn print_as_bytecode(node: &CallExpression) -> Vec<u8> {
     let num_of_args = bytes_from_32(Number::U32(num_of_args));
     bytecode.extend_from_slice(&num_of_args);
 
-    bytecode
+    Ok(bytecode)
 }
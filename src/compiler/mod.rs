@@ -1,12 +1,17 @@
 mod bytecode;
 mod handlers;
 
-use crate::ast::{module::ModuleAst, AstNodeType, Expression};
+use crate::{
+    ast::{module::ModuleAst, AstNodeType, Expression},
+    core::error::EgoError,
+};
 
 pub struct Compiler {}
 
 impl Compiler {
-    pub fn gen_bytecode(ast: ModuleAst) -> Vec<u8> {
+    pub fn gen_bytecode(ast: ModuleAst) -> Result<Vec<u8>, EgoError> {
+        handlers::reject_logical(&ast.children)?;
+
         let mut counter = 0;
         let mut bytecode: Vec<u8> = vec![];
 
@@ -14,8 +19,8 @@ impl Compiler {
             match &ast.children[counter] {
                 AstNodeType::Expression(expr) => match expr {
                     Expression::CallExpression(v) => {
-                        let call_expression_bytecode = match v.identifier.name.as_str() {
-                            "print" => handlers::print_as_bytecode(v),
+                        let call_expression_bytecode = match v.callee_name() {
+                            Some("print") => handlers::print_as_bytecode(v)?,
                             _ => {
                                 // todo: handle custom defined callable members
                                 vec![]
@@ -31,6 +36,6 @@ impl Compiler {
             counter += 1;
         }
 
-        bytecode
+        Ok(bytecode)
     }
 }
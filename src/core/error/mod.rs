@@ -1,38 +1,160 @@
+use std::fmt;
+
 use crate::log;
 
+use super::span::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ErrorType {
     SyntaxError,
     EgoUsageError,
     FatalError,
     ParsingError,
-    InterpretingError,
     ReferenceError,
+    RangeError,
+    ImportError,
+    IoError,
     StackUnderflowError,
     UnknownArithmeticOperator,
     TypeError,
     InvalidTypeAnnotation,
     CompilationError,
+    ConstantReassignmentError,
+    MatchError,
+    ArithmeticError,
+}
+
+impl ErrorType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorType::SyntaxError => "Syntax error:",
+            ErrorType::EgoUsageError => "Usage error:",
+            ErrorType::FatalError => "Fatal error:",
+            ErrorType::ParsingError => "Parsing error:",
+            ErrorType::ReferenceError => "Reference error:",
+            ErrorType::RangeError => "Range error:",
+            ErrorType::ImportError => "Import error:",
+            ErrorType::IoError => "IO error:",
+            ErrorType::StackUnderflowError => "Stack underflow error:",
+            ErrorType::UnknownArithmeticOperator => "Unknown arithmetic operator error:",
+            ErrorType::TypeError => "Type Error: ",
+            ErrorType::InvalidTypeAnnotation => "Invalid type annotation: ",
+            ErrorType::CompilationError => "Compilation error: ",
+            ErrorType::ConstantReassignmentError => "Constant reassignment error:",
+            ErrorType::MatchError => "Match error:",
+            ErrorType::ArithmeticError => "Arithmetic error:",
+        }
+    }
+}
+
+// errors raised while parsing or running ego code, returned
+// to the caller instead of exiting so embedders can recover
+#[derive(Debug, Clone, PartialEq)]
+pub struct EgoError {
+    pub error_type: ErrorType,
+    pub message: String,
+    pub span: Option<Span>,
+    // calls that led to the error, innermost first
+    pub trace: Vec<Frame>,
+}
+
+impl EgoError {
+    pub fn new(error_type: ErrorType, message: &str, span: Option<Span>) -> EgoError {
+        EgoError {
+            error_type,
+            message: message.to_string(),
+            span,
+            trace: vec![],
+        }
+    }
+
+    // scopes don't know about positions, so the caller
+    // fills it in when the error reaches a node
+    pub fn with_span(mut self, span: Span) -> EgoError {
+        if self.span.is_none() {
+            self.span = Some(span);
+        }
+        self
+    }
+
+    // the innermost call sets the trace, outer calls
+    // are already part of its frames
+    pub fn with_trace(mut self, trace: Vec<Frame>) -> EgoError {
+        if self.trace.is_empty() {
+            self.trace = trace;
+        }
+        self
+    }
+
+    // logs the error, used by the cli and the wasm
+    // playground which read the log history
+    pub fn report(&self) {
+        log!("\n[ego] {} {}", self.error_type.as_str(), self.message);
+        if let Some(span) = &self.span {
+            // offending source line with the span underlined
+            let gutter = " ".repeat(span.line.to_string().len());
+            log!("{gutter}--> {span}");
+            log!("{gutter} |");
+            log!("{} | {}", span.line, span.source_line());
+            log!("{gutter} | {}", span.underline());
+        }
+        // recursive calls are shown once with a count
+        let mut frames = self.trace.iter().map(|frame| frame.to_string()).peekable();
+        while let Some(frame) = frames.next() {
+            log!("      at {frame}");
+            let mut repeated = 0;
+            while frames.next_if_eq(&frame).is_some() {
+                repeated += 1;
+            }
+            if repeated > 0 {
+                log!("      ... repeated {repeated} more times");
+            }
+        }
+        log!(""); // space at the end
+    }
+
+    // only the cli binary is allowed to end the process
+    pub fn throw(&self) -> ! {
+        self.report();
+        std::process::exit(1);
+    }
+}
+
+impl fmt::Display for EgoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.error_type.as_str(), self.message)?;
+        if let Some(span) = &self.span {
+            write!(f, " ({span})")?;
+        }
+        Ok(())
+    }
 }
 
-pub fn throw(error_type: ErrorType, error_message: &str, line: Option<usize>) {
-    let error_string = match error_type {
-        ErrorType::SyntaxError => "Syntax error:",
-        ErrorType::EgoUsageError => "Usage error:",
-        ErrorType::FatalError => "Fatal error:",
-        ErrorType::ParsingError => "Parsing error:",
-        ErrorType::InterpretingError => "Interpreting error:",
-        ErrorType::ReferenceError => "Reference error:",
-        ErrorType::StackUnderflowError => "Stack underflow error:",
-        ErrorType::UnknownArithmeticOperator => "Unknown arithmetic operator error:",
-        ErrorType::TypeError => "Type Error: ",
-        ErrorType::InvalidTypeAnnotation => "Invalid type annotation: ",
-        ErrorType::CompilationError => "Compilation error: ",
-    };
-
-    log!("\n[ego] {error_string} {error_message}");
-    if let Some(line) = line {
-        log!("      └ on line: {line}");
-    }
-    log!(""); // space at the end
-    std::process::exit(1);
+impl std::error::Error for EgoError {}
+
+// a call to an ego function, the span is where it was
+// called from and it is missing for calls made by the host
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub function: String,
+    pub span: Option<Span>,
+}
+
+impl Frame {
+    pub fn new(function: &str, span: Option<Span>) -> Frame {
+        Frame {
+            function: function.to_string(),
+            span,
+        }
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.function)?;
+        if let Some(span) = &self.span {
+            write!(f, " ({span})")?;
+        }
+        Ok(())
+    }
 }
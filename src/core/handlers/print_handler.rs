@@ -1,16 +1,15 @@
 use crate::{
-    core::runtypes::{traits::print::Print, RuntimeType},
+    core::runtypes::{traits::print::Print, NativeResult, RuntimeType},
     log,
-    runtime::ScopesStack,
 };
 
-pub fn print(args: Vec<RuntimeType>, scopes: &ScopesStack) -> Option<RuntimeType> {
+pub fn print(args: &[RuntimeType]) -> NativeResult {
     let mut raw_values: Vec<String> = vec![];
     for arg in args {
-        raw_values.push(arg.print(scopes));
+        raw_values.push(arg.print());
     }
 
     let string = raw_values.join(" ");
     log!("{string}");
-    None
+    Ok(RuntimeType::nothing())
 }
@@ -2,3 +2,5 @@ pub mod error;
 pub mod handlers;
 pub mod logs;
 pub mod runtypes;
+pub mod span;
+pub mod stdlib;
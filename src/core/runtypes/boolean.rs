@@ -26,12 +26,18 @@ impl RnBoolean {
             RuntimeType::Nothing(_) => RuntimeType::nothing(), // nothing + true -> nothing
             RuntimeType::RnString(s) => {
                 // true + "world" -> "trueworld"
-                RuntimeType::string(format!("{}{}", self.to_string(), s.to_string()), true)
+                RuntimeType::string(format!("{}{}", self.to_string(), s.to_string()))
             }
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(_) => RuntimeType::nothing(),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn substract(&self, operand: RuntimeType) -> RuntimeType {
@@ -40,8 +46,14 @@ impl RnBoolean {
             RuntimeType::RnString(_) => RuntimeType::nothing(),
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(_) => RuntimeType::nothing(),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn mulitply(&self, operand: RuntimeType) -> RuntimeType {
@@ -50,8 +62,14 @@ impl RnBoolean {
             RuntimeType::RnString(_) => RuntimeType::nothing(),
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(_) => RuntimeType::nothing(),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn divide(&self, operand: RuntimeType) -> RuntimeType {
@@ -60,18 +78,39 @@ impl RnBoolean {
             RuntimeType::RnString(_) => RuntimeType::nothing(),
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(_) => RuntimeType::nothing(),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
+    pub fn int_divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn modulo(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn power(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
     pub fn greater_than(&self, operand: RuntimeType) -> RuntimeType {
         match operand {
             RuntimeType::Nothing(_) => RuntimeType::boolean(false),
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn less_than(&self, operand: RuntimeType) -> RuntimeType {
@@ -80,8 +119,14 @@ impl RnBoolean {
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn greater_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -90,8 +135,14 @@ impl RnBoolean {
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(v) => RuntimeType::boolean(self.val == v.val),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn less_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -100,8 +151,14 @@ impl RnBoolean {
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(v) => RuntimeType::boolean(self.val == v.val),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn not_equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -110,8 +167,14 @@ impl RnBoolean {
             RuntimeType::RnString(_) => RuntimeType::boolean(true),
             RuntimeType::RnBoolean(v) => RuntimeType::boolean(self.val != v.val),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(true),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(true),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(true),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(true),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(true),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(true),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(true),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(true),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(true),
         }
     }
     pub fn equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -120,19 +183,14 @@ impl RnBoolean {
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(v) => RuntimeType::boolean(self.val == v.val),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
-        }
-    }
-    pub fn or(&self, _operand: RuntimeType) -> RuntimeType {
-        match _operand {
-            _ => RuntimeType::boolean(true),
-        }
-    }
-    pub fn and(&self, _operand: RuntimeType) -> RuntimeType {
-        match _operand {
-            RuntimeType::Nothing(_) => RuntimeType::boolean(false),
-            _ => RuntimeType::boolean(true),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
 }
@@ -0,0 +1,28 @@
+use crate::ast::enum_declaration::EnumVariant;
+
+// value bound to the name of an enum declaration
+#[derive(Debug, Clone)]
+pub struct RnEnum {
+    pub name: String,
+    pub variants: Vec<EnumVariant>,
+}
+
+impl RnEnum {
+    pub fn new(name: String, variants: Vec<EnumVariant>) -> RnEnum {
+        RnEnum { name, variants }
+    }
+
+    pub fn to_string(&self) -> String {
+        format!("enum {}", self.name)
+    }
+
+    pub fn to_boolean(&self) -> bool {
+        true
+    }
+
+    pub fn variant(&self, name: &str) -> Option<&EnumVariant> {
+        self.variants
+            .iter()
+            .find(|variant| variant.identifier.name == name)
+    }
+}
@@ -1,4 +1,8 @@
-use crate::ast::{block::Block, identifier::Identifier};
+use crate::{
+    ast::{block::Block, identifier::Identifier, Type},
+    core::span::Span,
+    runtime::Environment,
+};
 
 use super::identifier::RnIdentifier;
 
@@ -6,25 +10,29 @@ use super::identifier::RnIdentifier;
 pub struct RnFunction {
     pub identifier: RnIdentifier,
     pub parameters: Vec<Identifier>,
+    pub return_type: Option<Type>,
     pub body: Block,
-    pub at: usize,
-    pub line: usize,
+    pub span: Span,
+    // scopes where the function was defined
+    pub environment: Environment,
 }
 
 impl RnFunction {
     pub fn new(
         identifier: RnIdentifier,
         parameters: Vec<Identifier>,
+        return_type: Option<Type>,
         body: Block,
-        at: usize,
-        line: usize,
+        span: Span,
+        environment: Environment,
     ) -> RnFunction {
         RnFunction {
             identifier,
             parameters,
+            return_type,
             body,
-            at,
-            line,
+            span,
+            environment,
         }
     }
 
@@ -33,6 +41,6 @@ impl RnFunction {
     }
 
     pub fn to_boolean(&self) -> bool {
-        false
+        true
     }
 }
@@ -0,0 +1,85 @@
+use std::rc::Rc;
+
+use super::{object::RnObject, structure::RnStruct, RuntimeType};
+
+// value created by Point { x: 1, y: 2 }
+#[derive(Debug, Clone)]
+pub struct RnInstance {
+    pub definition: Rc<RnStruct>,
+    pub fields: RnObject,
+}
+
+impl RnInstance {
+    pub fn new(definition: Rc<RnStruct>, fields: Vec<(String, RuntimeType)>) -> RnInstance {
+        RnInstance {
+            definition,
+            fields: RnObject::new(fields),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    pub fn to_string(&self) -> String {
+        format!("{} {}", self.name(), self.fields.to_string())
+    }
+
+    pub fn to_boolean(&self) -> bool {
+        true
+    }
+
+    // instances of different structs are never equal
+    fn equals(&self, other: &RnInstance) -> bool {
+        self.name() == other.name() && self.fields.equals(&other.fields)
+    }
+}
+
+// implement arithmetics
+impl RnInstance {
+    pub fn add(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn substract(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn mulitply(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn int_divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn modulo(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn power(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn greater_than(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::boolean(false)
+    }
+    pub fn less_than(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::boolean(false)
+    }
+    pub fn greater_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
+        self.equal(operand)
+    }
+    pub fn less_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
+        self.equal(operand)
+    }
+    pub fn not_equal(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::RnInstance(i) => RuntimeType::boolean(!self.equals(&i)),
+            _ => RuntimeType::boolean(true),
+        }
+    }
+    pub fn equal(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::RnInstance(i) => RuntimeType::boolean(self.equals(&i)),
+            _ => RuntimeType::boolean(false),
+        }
+    }
+}
@@ -1,10 +1,24 @@
 mod boolean;
+mod enumeration;
 mod function;
 mod identifier;
+mod instance;
+mod native_function;
 mod nothing;
 mod number;
+mod object;
 mod runtime_type;
 mod string;
+mod structure;
 pub mod traits;
+mod variant;
+mod vector;
 
+pub use self::enumeration::RnEnum;
+pub use self::function::RnFunction;
+pub use self::identifier::RnIdentifier;
+pub use self::native_function::{NativeResult, RnNativeFunction};
+pub use self::object::RnObject;
 pub use self::runtime_type::RuntimeType;
+#[allow(unused_imports)]
+pub use self::structure::RnStruct;
@@ -0,0 +1,65 @@
+use std::{fmt, rc::Rc};
+
+use crate::core::error::ErrorType;
+
+use super::RuntimeType;
+
+pub type NativeResult = Result<RuntimeType, (ErrorType, String)>;
+pub type NativeHandler = Rc<dyn Fn(&[RuntimeType]) -> NativeResult>;
+
+// functions implemented in rust and called from ego code
+#[derive(Clone)]
+pub struct RnNativeFunction {
+    pub name: String,
+    pub arity: Option<usize>, // none for any number of arguments
+    pub handler: NativeHandler,
+}
+
+impl RnNativeFunction {
+    pub fn new<F>(name: &str, arity: Option<usize>, handler: F) -> RnNativeFunction
+    where
+        F: Fn(&[RuntimeType]) -> NativeResult + 'static,
+    {
+        RnNativeFunction {
+            name: name.to_string(),
+            arity,
+            handler: Rc::new(handler),
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn to_boolean(&self) -> bool {
+        true
+    }
+
+    pub fn call(&self, arguments: &[RuntimeType]) -> NativeResult {
+        if let Some(arity) = self.arity {
+            if arguments.len() != arity {
+                return Err((
+                    ErrorType::TypeError,
+                    format!(
+                        "{}(...) expects {} argument(s) but got {}",
+                        self.name,
+                        arity,
+                        arguments.len()
+                    ),
+                ));
+            }
+        }
+
+        (self.handler)(arguments)
+            .map_err(|(error_type, message)| (error_type, format!("{}(...): {message}", self.name)))
+    }
+}
+
+impl fmt::Debug for RnNativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RnNativeFunction")
+            .field("name", &self.name)
+            .field("arity", &self.arity)
+            .finish()
+    }
+}
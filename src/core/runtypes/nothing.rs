@@ -30,6 +30,15 @@ impl Nothing {
     pub fn divide(&self, _operand: RuntimeType) -> RuntimeType {
         RuntimeType::nothing()
     }
+    pub fn int_divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn modulo(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn power(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
     pub fn greater_than(&self, _operand: RuntimeType) -> RuntimeType {
         RuntimeType::boolean(false)
     }
@@ -54,15 +63,4 @@ impl Nothing {
             _ => RuntimeType::boolean(false),
         }
     }
-    pub fn or(&self, _operand: RuntimeType) -> RuntimeType {
-        match _operand {
-            RuntimeType::Nothing(_) => RuntimeType::boolean(false),
-            _ => RuntimeType::boolean(true),
-        }
-    }
-    pub fn and(&self, _operand: RuntimeType) -> RuntimeType {
-        match _operand {
-            _ => RuntimeType::boolean(false),
-        }
-    }
 }
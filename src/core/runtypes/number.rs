@@ -14,12 +14,9 @@ impl RnNumber {
         self.val.to_string()
     }
 
+    // only 0 and NaN are falsy
     pub fn to_boolean(&self) -> bool {
-        if self.val > 0.0 {
-            true
-        } else {
-            false
-        }
+        self.val != 0.0 && !self.val.is_nan()
     }
 }
 
@@ -30,12 +27,18 @@ impl RnNumber {
             RuntimeType::Nothing(_) => RuntimeType::nothing(),
             RuntimeType::RnString(s) => {
                 // 13 + "hello" -> "13hello"
-                RuntimeType::string(format!("{}{}", self.to_string(), s.to_string()), true)
+                RuntimeType::string(format!("{}{}", self.to_string(), s.to_string()))
             }
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(n) => RuntimeType::number(self.val + n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn substract(&self, operand: RuntimeType) -> RuntimeType {
@@ -44,8 +47,14 @@ impl RnNumber {
             RuntimeType::RnString(_) => RuntimeType::nothing(),
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(n) => RuntimeType::number(self.val - n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn mulitply(&self, operand: RuntimeType) -> RuntimeType {
@@ -54,8 +63,14 @@ impl RnNumber {
             RuntimeType::RnString(_) => RuntimeType::nothing(),
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(n) => RuntimeType::number(self.val * n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn divide(&self, operand: RuntimeType) -> RuntimeType {
@@ -64,8 +79,65 @@ impl RnNumber {
             RuntimeType::RnString(_) => RuntimeType::nothing(),
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(n) => RuntimeType::number(self.val / n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
+        }
+    }
+    pub fn int_divide(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::Nothing(_) => RuntimeType::nothing(),
+            RuntimeType::RnString(_) => RuntimeType::nothing(),
+            RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
+            RuntimeType::RnNumber(n) => RuntimeType::number((self.val / n.val).floor()),
+            RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
+        }
+    }
+    // takes the sign of the divisor so it matches int_divide
+    pub fn modulo(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::Nothing(_) => RuntimeType::nothing(),
+            RuntimeType::RnString(_) => RuntimeType::nothing(),
+            RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
+            RuntimeType::RnNumber(n) => {
+                RuntimeType::number(self.val - n.val * (self.val / n.val).floor())
+            }
+            RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
+        }
+    }
+    pub fn power(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::Nothing(_) => RuntimeType::nothing(),
+            RuntimeType::RnString(_) => RuntimeType::nothing(),
+            RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
+            RuntimeType::RnNumber(n) => RuntimeType::number(self.val.powf(n.val)),
+            RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn greater_than(&self, operand: RuntimeType) -> RuntimeType {
@@ -74,8 +146,14 @@ impl RnNumber {
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(n) => RuntimeType::boolean(self.val > n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn less_than(&self, operand: RuntimeType) -> RuntimeType {
@@ -84,8 +162,14 @@ impl RnNumber {
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(n) => RuntimeType::boolean(self.val < n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn greater_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -94,8 +178,14 @@ impl RnNumber {
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(n) => RuntimeType::boolean(self.val >= n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn less_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -104,8 +194,14 @@ impl RnNumber {
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(n) => RuntimeType::boolean(self.val <= n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn not_equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -114,8 +210,14 @@ impl RnNumber {
             RuntimeType::RnString(_) => RuntimeType::boolean(true),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(true),
             RuntimeType::RnNumber(n) => RuntimeType::boolean(self.val != n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(true),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(true),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(true),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(true),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(true),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(true),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(true),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(true),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(true),
         }
     }
     pub fn equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -124,19 +226,14 @@ impl RnNumber {
             RuntimeType::RnString(_) => RuntimeType::boolean(false),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(n) => RuntimeType::boolean(self.val == n.val),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
-        }
-    }
-    pub fn or(&self, _operand: RuntimeType) -> RuntimeType {
-        match _operand {
-            _ => RuntimeType::boolean(true),
-        }
-    }
-    pub fn and(&self, _operand: RuntimeType) -> RuntimeType {
-        match _operand {
-            RuntimeType::Nothing(_) => RuntimeType::boolean(false),
-            _ => RuntimeType::boolean(true),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
 }
@@ -0,0 +1,120 @@
+use super::{traits::arithmetic::Arithmetic, RuntimeType};
+
+#[derive(Debug, Clone)]
+pub struct RnObject {
+    // keeps declaration order when printing
+    pub properties: Vec<(String, RuntimeType)>,
+}
+
+impl RnObject {
+    pub fn new(properties: Vec<(String, RuntimeType)>) -> RnObject {
+        RnObject { properties }
+    }
+
+    pub fn to_string(&self) -> String {
+        if self.properties.len() < 1 {
+            return "{}".to_string();
+        }
+
+        let properties: Vec<String> = self
+            .properties
+            .iter()
+            .map(|(key, value)| match value {
+                // quote strings to tell apart { a: "1" } from { a: 1 }
+                RuntimeType::RnString(s) => format!("{key}: \"{}\"", s.to_string()),
+                _ => format!("{key}: {}", value.to_string()),
+            })
+            .collect();
+        format!("{{ {} }}", properties.join(", "))
+    }
+
+    pub fn to_boolean(&self) -> bool {
+        true
+    }
+
+    pub fn get(&self, key: &str) -> Option<&RuntimeType> {
+        self.properties
+            .iter()
+            .find(|(property, _)| property == key)
+            .map(|(_, value)| value)
+    }
+
+    // updates the property or adds it if it's not defined yet
+    pub fn set(&mut self, key: String, value: RuntimeType) {
+        if let Some(property) = self
+            .properties
+            .iter_mut()
+            .find(|(property, _)| *property == key)
+        {
+            property.1 = value;
+        } else {
+            self.properties.push((key, value));
+        }
+    }
+
+    // same keys with equal values, no matter the order
+    pub fn equals(&self, other: &RnObject) -> bool {
+        if self.properties.len() != other.properties.len() {
+            return false;
+        }
+
+        self.properties
+            .iter()
+            .all(|(key, value)| match other.get(key) {
+                Some(other_value) => match value.arithmetic("==", other_value.clone()) {
+                    Ok(result) => result.to_boolean(),
+                    Err(_) => false,
+                },
+                None => false,
+            })
+    }
+}
+
+// implement arithmetics
+impl RnObject {
+    pub fn add(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn substract(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn mulitply(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn int_divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn modulo(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn power(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn greater_than(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::boolean(false)
+    }
+    pub fn less_than(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::boolean(false)
+    }
+    pub fn greater_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
+        self.equal(operand)
+    }
+    pub fn less_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
+        self.equal(operand)
+    }
+    pub fn not_equal(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::RnObject(o) => RuntimeType::boolean(!self.equals(&o)),
+            _ => RuntimeType::boolean(true),
+        }
+    }
+    pub fn equal(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::RnObject(o) => RuntimeType::boolean(self.equals(&o)),
+            _ => RuntimeType::boolean(false),
+        }
+    }
+}
@@ -1,19 +1,29 @@
-use std::fmt::{self};
+use std::{
+    fmt::{self},
+    rc::Rc,
+};
 
 use crate::{
-    ast::{block::Block, identifier::Identifier},
-    core::error::ErrorType,
-    runtime::ScopesStack,
+    ast::{block::Block, enum_declaration::EnumVariant, identifier::Identifier, Type},
+    core::{error::ErrorType, span::Span},
+    runtime::Environment,
 };
 
 use super::{
     boolean::RnBoolean,
+    enumeration::RnEnum,
     function::RnFunction,
     identifier::RnIdentifier,
+    instance::RnInstance,
+    native_function::RnNativeFunction,
     nothing::Nothing,
     number::RnNumber,
+    object::RnObject,
     string::RnString,
+    structure::RnStruct,
     traits::{arithmetic::Arithmetic, print::Print},
+    variant::RnVariant,
+    vector::RnVector,
 };
 
 #[derive(Debug, Clone)]
@@ -21,9 +31,15 @@ pub enum RuntimeType {
     Nothing(Nothing),
     RnString(RnString),
     RnNumber(RnNumber),
-    RnIdentifier(RnIdentifier),
     RnBoolean(RnBoolean),
     RnFunction(RnFunction),
+    RnNativeFunction(RnNativeFunction),
+    RnVector(RnVector),
+    RnObject(RnObject),
+    RnStruct(RnStruct),
+    RnInstance(RnInstance),
+    RnEnum(RnEnum),
+    RnVariant(RnVariant),
 }
 
 impl RuntimeType {
@@ -31,33 +47,65 @@ impl RuntimeType {
         RuntimeType::Nothing(Nothing::new())
     }
 
-    pub fn string(value: String, raw: bool) -> RuntimeType {
-        RuntimeType::RnString(RnString::new(value, raw))
+    pub fn string(value: String) -> RuntimeType {
+        RuntimeType::RnString(RnString::new(value))
     }
 
     pub fn number(value: f64) -> RuntimeType {
         RuntimeType::RnNumber(RnNumber::new(value))
     }
 
-    pub fn identifier(value: String) -> RuntimeType {
-        RuntimeType::RnIdentifier(RnIdentifier::new(value))
-    }
-
     pub fn boolean(value: bool) -> RuntimeType {
         RuntimeType::RnBoolean(RnBoolean::new(value))
     }
 
+    pub fn vector(elements: Vec<RuntimeType>) -> RuntimeType {
+        RuntimeType::RnVector(RnVector::new(elements))
+    }
+
+    pub fn object(properties: Vec<(String, RuntimeType)>) -> RuntimeType {
+        RuntimeType::RnObject(RnObject::new(properties))
+    }
+
+    pub fn structure(name: String, fields: Vec<Identifier>) -> RuntimeType {
+        RuntimeType::RnStruct(RnStruct::new(name, fields))
+    }
+
+    pub fn instance(definition: Rc<RnStruct>, fields: Vec<(String, RuntimeType)>) -> RuntimeType {
+        RuntimeType::RnInstance(RnInstance::new(definition, fields))
+    }
+
+    pub fn enumeration(name: String, variants: Vec<EnumVariant>) -> RuntimeType {
+        RuntimeType::RnEnum(RnEnum::new(name, variants))
+    }
+
+    pub fn variant(
+        definition: Rc<RnEnum>,
+        name: String,
+        fields: Vec<(String, RuntimeType)>,
+    ) -> RuntimeType {
+        RuntimeType::RnVariant(RnVariant::new(definition, name, fields))
+    }
+
     // has ast nodes dependencies
     // in its fields
     pub fn function(
         identifier: String,
         parameters: Vec<Identifier>,
+        return_type: Option<Type>,
         body: Block,
-        at: usize,
-        line: usize,
+        span: Span,
+        environment: Environment,
     ) -> RuntimeType {
         let identifier = RnIdentifier::new(identifier);
-        RuntimeType::RnFunction(RnFunction::new(identifier, parameters, body, at, line))
+        RuntimeType::RnFunction(RnFunction::new(
+            identifier,
+            parameters,
+            return_type,
+            body,
+            span,
+            environment,
+        ))
     }
 
     pub fn to_string(&self) -> String {
@@ -65,9 +113,35 @@ impl RuntimeType {
             RuntimeType::Nothing(nothing) => nothing.to_string(),
             RuntimeType::RnString(rn_string) => rn_string.to_string(),
             RuntimeType::RnNumber(rn_number) => rn_number.to_string(),
-            RuntimeType::RnIdentifier(rn_identifier) => rn_identifier.to_string(),
             RuntimeType::RnBoolean(rn_boolean) => rn_boolean.to_string(),
             RuntimeType::RnFunction(rn_function) => rn_function.to_string(),
+            RuntimeType::RnNativeFunction(rn_function) => rn_function.to_string(),
+            RuntimeType::RnVector(rn_vector) => rn_vector.to_string(),
+            RuntimeType::RnObject(rn_object) => rn_object.to_string(),
+            RuntimeType::RnStruct(rn_struct) => rn_struct.to_string(),
+            RuntimeType::RnInstance(rn_instance) => rn_instance.to_string(),
+            RuntimeType::RnEnum(rn_enum) => rn_enum.to_string(),
+            RuntimeType::RnVariant(rn_variant) => rn_variant.to_string(),
+        }
+    }
+
+    // name of the type as seen from ego code
+    pub fn type_name(&self) -> String {
+        match self {
+            RuntimeType::Nothing(_) => "nothing".to_string(),
+            RuntimeType::RnBoolean(_) => "boolean".to_string(),
+            RuntimeType::RnNumber(_) => "number".to_string(),
+            RuntimeType::RnString(_) => "string".to_string(),
+            RuntimeType::RnFunction(_) => "function".to_string(),
+            RuntimeType::RnNativeFunction(_) => "function".to_string(),
+            RuntimeType::RnVector(_) => "vector".to_string(),
+            RuntimeType::RnObject(_) => "object".to_string(),
+            RuntimeType::RnStruct(_) => "struct".to_string(),
+            // instances are named after their struct
+            RuntimeType::RnInstance(rn_instance) => rn_instance.name().to_string(),
+            RuntimeType::RnEnum(_) => "enum".to_string(),
+            // and variants after their enum
+            RuntimeType::RnVariant(rn_variant) => rn_variant.enum_name().to_string(),
         }
     }
 
@@ -76,9 +150,15 @@ impl RuntimeType {
             RuntimeType::Nothing(nothing) => nothing.to_boolean(),
             RuntimeType::RnString(rn_string) => rn_string.to_boolean(),
             RuntimeType::RnNumber(rn_number) => rn_number.to_boolean(),
-            RuntimeType::RnIdentifier(rn_identifier) => rn_identifier.to_boolean(),
             RuntimeType::RnBoolean(rn_boolean) => rn_boolean.to_boolean(),
             RuntimeType::RnFunction(rn_function) => rn_function.to_boolean(),
+            RuntimeType::RnNativeFunction(rn_function) => rn_function.to_boolean(),
+            RuntimeType::RnVector(rn_vector) => rn_vector.to_boolean(),
+            RuntimeType::RnObject(rn_object) => rn_object.to_boolean(),
+            RuntimeType::RnStruct(rn_struct) => rn_struct.to_boolean(),
+            RuntimeType::RnInstance(rn_instance) => rn_instance.to_boolean(),
+            RuntimeType::RnEnum(rn_enum) => rn_enum.to_boolean(),
+            RuntimeType::RnVariant(rn_variant) => rn_variant.to_boolean(),
         }
     }
 }
@@ -89,23 +169,35 @@ impl fmt::Display for RuntimeType {
             RuntimeType::Nothing(_) => write!(f, "Nothing"),
             RuntimeType::RnString(_) => write!(f, "RnString"),
             RuntimeType::RnNumber(_) => write!(f, "RnNumber"),
-            RuntimeType::RnIdentifier(_) => write!(f, "RnIdentifier"),
             RuntimeType::RnBoolean(_) => write!(f, "RnBoolean"),
             RuntimeType::RnFunction(_) => write!(f, "RnFunction"),
+            RuntimeType::RnNativeFunction(_) => write!(f, "RnNativeFunction"),
+            RuntimeType::RnVector(_) => write!(f, "RnVector"),
+            RuntimeType::RnObject(_) => write!(f, "RnObject"),
+            RuntimeType::RnStruct(_) => write!(f, "RnStruct"),
+            RuntimeType::RnInstance(_) => write!(f, "RnInstance"),
+            RuntimeType::RnEnum(_) => write!(f, "RnEnum"),
+            RuntimeType::RnVariant(_) => write!(f, "RnVariant"),
         }
     }
 }
 
 // Traits defined for runtime types
 impl Print for RuntimeType {
-    fn print(&self, scopes: &ScopesStack) -> String {
+    fn print(&self) -> String {
         match self {
             RuntimeType::Nothing(t) => t.to_string(),
             RuntimeType::RnString(t) => t.to_string(),
             RuntimeType::RnNumber(t) => t.to_string(),
             RuntimeType::RnBoolean(t) => t.to_string(),
-            RuntimeType::RnIdentifier(t) => t.resolve(scopes).to_string(),
             RuntimeType::RnFunction(t) => t.to_string(),
+            RuntimeType::RnNativeFunction(t) => t.to_string(),
+            RuntimeType::RnVector(t) => t.to_string(),
+            RuntimeType::RnObject(t) => t.to_string(),
+            RuntimeType::RnStruct(t) => t.to_string(),
+            RuntimeType::RnInstance(t) => t.to_string(),
+            RuntimeType::RnEnum(t) => t.to_string(),
+            RuntimeType::RnVariant(t) => t.to_string(),
         }
     }
 }
@@ -118,14 +210,15 @@ impl Arithmetic for RuntimeType {
                 "-" => Ok(v.substract(operand)),
                 "*" => Ok(v.mulitply(operand)),
                 "/" => Ok(v.divide(operand)),
+                "~/" => Ok(v.int_divide(operand)),
+                "%" => Ok(v.modulo(operand)),
+                "**" => Ok(v.power(operand)),
                 ">" => Ok(v.greater_than(operand)),
                 "<" => Ok(v.less_than(operand)),
                 ">=" => Ok(v.greater_than_or_equal(operand)),
                 "<=" => Ok(v.less_than_or_equal(operand)),
                 "!=" => Ok(v.not_equal(operand)),
                 "==" => Ok(v.equal(operand)),
-                "|" => Ok(v.or(operand)),
-                "&" => Ok(v.and(operand)),
                 _ => Err(ErrorType::UnknownArithmeticOperator),
             },
             RuntimeType::RnNumber(v) => match operator {
@@ -133,14 +226,15 @@ impl Arithmetic for RuntimeType {
                 "-" => Ok(v.substract(operand)),
                 "*" => Ok(v.mulitply(operand)),
                 "/" => Ok(v.divide(operand)),
+                "~/" => Ok(v.int_divide(operand)),
+                "%" => Ok(v.modulo(operand)),
+                "**" => Ok(v.power(operand)),
                 ">" => Ok(v.greater_than(operand)),
                 "<" => Ok(v.less_than(operand)),
                 ">=" => Ok(v.greater_than_or_equal(operand)),
                 "<=" => Ok(v.less_than_or_equal(operand)),
                 "!=" => Ok(v.not_equal(operand)),
                 "==" => Ok(v.equal(operand)),
-                "|" => Ok(v.or(operand)),
-                "&" => Ok(v.and(operand)),
                 _ => Err(ErrorType::UnknownArithmeticOperator),
             },
             RuntimeType::RnString(v) => match operator {
@@ -148,14 +242,15 @@ impl Arithmetic for RuntimeType {
                 "-" => Ok(v.substract(operand)),
                 "*" => Ok(v.mulitply(operand)),
                 "/" => Ok(v.divide(operand)),
+                "~/" => Ok(v.int_divide(operand)),
+                "%" => Ok(v.modulo(operand)),
+                "**" => Ok(v.power(operand)),
                 ">" => Ok(v.greater_than(operand)),
                 "<" => Ok(v.less_than(operand)),
                 ">=" => Ok(v.greater_than_or_equal(operand)),
                 "<=" => Ok(v.less_than_or_equal(operand)),
                 "!=" => Ok(v.not_equal(operand)),
                 "==" => Ok(v.equal(operand)),
-                "|" => Ok(v.or(operand)),
-                "&" => Ok(v.and(operand)),
                 _ => Err(ErrorType::UnknownArithmeticOperator),
             },
             RuntimeType::RnBoolean(v) => match operator {
@@ -163,14 +258,79 @@ impl Arithmetic for RuntimeType {
                 "-" => Ok(v.substract(operand)),
                 "*" => Ok(v.mulitply(operand)),
                 "/" => Ok(v.divide(operand)),
+                "~/" => Ok(v.int_divide(operand)),
+                "%" => Ok(v.modulo(operand)),
+                "**" => Ok(v.power(operand)),
+                ">" => Ok(v.greater_than(operand)),
+                "<" => Ok(v.less_than(operand)),
+                ">=" => Ok(v.greater_than_or_equal(operand)),
+                "<=" => Ok(v.less_than_or_equal(operand)),
+                "!=" => Ok(v.not_equal(operand)),
+                "==" => Ok(v.equal(operand)),
+                _ => Err(ErrorType::UnknownArithmeticOperator),
+            },
+            RuntimeType::RnVector(v) => match operator {
+                "+" => Ok(v.add(operand)),
+                "-" => Ok(v.substract(operand)),
+                "*" => Ok(v.mulitply(operand)),
+                "/" => Ok(v.divide(operand)),
+                "~/" => Ok(v.int_divide(operand)),
+                "%" => Ok(v.modulo(operand)),
+                "**" => Ok(v.power(operand)),
+                ">" => Ok(v.greater_than(operand)),
+                "<" => Ok(v.less_than(operand)),
+                ">=" => Ok(v.greater_than_or_equal(operand)),
+                "<=" => Ok(v.less_than_or_equal(operand)),
+                "!=" => Ok(v.not_equal(operand)),
+                "==" => Ok(v.equal(operand)),
+                _ => Err(ErrorType::UnknownArithmeticOperator),
+            },
+            RuntimeType::RnObject(v) => match operator {
+                "+" => Ok(v.add(operand)),
+                "-" => Ok(v.substract(operand)),
+                "*" => Ok(v.mulitply(operand)),
+                "/" => Ok(v.divide(operand)),
+                "~/" => Ok(v.int_divide(operand)),
+                "%" => Ok(v.modulo(operand)),
+                "**" => Ok(v.power(operand)),
+                ">" => Ok(v.greater_than(operand)),
+                "<" => Ok(v.less_than(operand)),
+                ">=" => Ok(v.greater_than_or_equal(operand)),
+                "<=" => Ok(v.less_than_or_equal(operand)),
+                "!=" => Ok(v.not_equal(operand)),
+                "==" => Ok(v.equal(operand)),
+                _ => Err(ErrorType::UnknownArithmeticOperator),
+            },
+            RuntimeType::RnInstance(v) => match operator {
+                "+" => Ok(v.add(operand)),
+                "-" => Ok(v.substract(operand)),
+                "*" => Ok(v.mulitply(operand)),
+                "/" => Ok(v.divide(operand)),
+                "~/" => Ok(v.int_divide(operand)),
+                "%" => Ok(v.modulo(operand)),
+                "**" => Ok(v.power(operand)),
+                ">" => Ok(v.greater_than(operand)),
+                "<" => Ok(v.less_than(operand)),
+                ">=" => Ok(v.greater_than_or_equal(operand)),
+                "<=" => Ok(v.less_than_or_equal(operand)),
+                "!=" => Ok(v.not_equal(operand)),
+                "==" => Ok(v.equal(operand)),
+                _ => Err(ErrorType::UnknownArithmeticOperator),
+            },
+            RuntimeType::RnVariant(v) => match operator {
+                "+" => Ok(v.add(operand)),
+                "-" => Ok(v.substract(operand)),
+                "*" => Ok(v.mulitply(operand)),
+                "/" => Ok(v.divide(operand)),
+                "~/" => Ok(v.int_divide(operand)),
+                "%" => Ok(v.modulo(operand)),
+                "**" => Ok(v.power(operand)),
                 ">" => Ok(v.greater_than(operand)),
                 "<" => Ok(v.less_than(operand)),
                 ">=" => Ok(v.greater_than_or_equal(operand)),
                 "<=" => Ok(v.less_than_or_equal(operand)),
                 "!=" => Ok(v.not_equal(operand)),
                 "==" => Ok(v.equal(operand)),
-                "|" => Ok(v.or(operand)),
-                "&" => Ok(v.and(operand)),
                 _ => Err(ErrorType::UnknownArithmeticOperator),
             },
             // RuntimeType::RnIdentifier(t) => t.resolve(scopes).to_string()
@@ -3,31 +3,19 @@ use super::RuntimeType;
 #[derive(Debug, Clone)]
 pub struct RnString {
     pub val: String,
-    pub raw: bool,
 }
 
 impl RnString {
-    pub fn new(value: String, raw: bool) -> RnString {
-        RnString { val: value, raw }
+    pub fn new(value: String) -> RnString {
+        RnString { val: value }
     }
 
     pub fn to_string(&self) -> String {
-        if self.raw {
-            return self.val.clone();
-        }
-
-        let mut chars = self.val.chars();
-        chars.next();
-        chars.next_back();
-        chars.collect()
+        self.val.clone()
     }
 
     pub fn to_boolean(&self) -> bool {
-        if self.val.len() > 0 {
-            true
-        } else {
-            false
-        }
+        !self.val.is_empty()
     }
 }
 
@@ -38,18 +26,24 @@ impl RnString {
             RuntimeType::Nothing(_) => RuntimeType::nothing(), // nothing + nothing -> nothing
             RuntimeType::RnString(s) => {
                 // "hello" + "world" -> "helloworld"
-                RuntimeType::string(format!("{}{}", self.to_string(), s.to_string()), true)
+                RuntimeType::string(format!("{}{}", self.to_string(), s.to_string()))
             }
             RuntimeType::RnBoolean(b) => {
                 // "hello" + true -> "hellotrue"
-                RuntimeType::string(format!("{}{}", self.to_string(), b.to_string()), true)
+                RuntimeType::string(format!("{}{}", self.to_string(), b.to_string()))
             }
             RuntimeType::RnNumber(n) => {
                 // "hello" + 13-> "hello13"
-                RuntimeType::string(format!("{}{}", self.to_string(), n.to_string()), true)
+                RuntimeType::string(format!("{}{}", self.to_string(), n.to_string()))
             }
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn substract(&self, operand: RuntimeType) -> RuntimeType {
@@ -58,8 +52,14 @@ impl RnString {
             RuntimeType::RnString(_) => RuntimeType::nothing(),
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(_) => RuntimeType::nothing(),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn mulitply(&self, operand: RuntimeType) -> RuntimeType {
@@ -68,8 +68,14 @@ impl RnString {
             RuntimeType::RnString(_) => RuntimeType::nothing(),
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(_) => RuntimeType::nothing(),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
     pub fn divide(&self, operand: RuntimeType) -> RuntimeType {
@@ -78,18 +84,39 @@ impl RnString {
             RuntimeType::RnString(_) => RuntimeType::nothing(),
             RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
             RuntimeType::RnNumber(_) => RuntimeType::nothing(),
-            RuntimeType::RnIdentifier(_) => RuntimeType::nothing(),
             RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
         }
     }
+    pub fn int_divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn modulo(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn power(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
     pub fn greater_than(&self, operand: RuntimeType) -> RuntimeType {
         match operand {
             RuntimeType::Nothing(_) => RuntimeType::boolean(false),
             RuntimeType::RnString(v) => RuntimeType::boolean(self.val.len() > v.val.len()),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn less_than(&self, operand: RuntimeType) -> RuntimeType {
@@ -98,8 +125,14 @@ impl RnString {
             RuntimeType::RnString(v) => RuntimeType::boolean(self.val.len() < v.val.len()),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn greater_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -108,8 +141,14 @@ impl RnString {
             RuntimeType::RnString(v) => RuntimeType::boolean(self.val.len() >= v.val.len()),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn less_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -118,8 +157,14 @@ impl RnString {
             RuntimeType::RnString(v) => RuntimeType::boolean(self.val.len() <= v.val.len()),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
     pub fn not_equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -128,8 +173,14 @@ impl RnString {
             RuntimeType::RnString(v) => RuntimeType::boolean(self.val != v.val),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(true),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(true),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(true),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(true),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(true),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(true),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(true),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(true),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(true),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(true),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(true),
         }
     }
     pub fn equal(&self, operand: RuntimeType) -> RuntimeType {
@@ -138,19 +189,14 @@ impl RnString {
             RuntimeType::RnString(v) => RuntimeType::boolean(self.val == v.val),
             RuntimeType::RnBoolean(_) => RuntimeType::boolean(false),
             RuntimeType::RnNumber(_) => RuntimeType::boolean(false),
-            RuntimeType::RnIdentifier(_) => RuntimeType::boolean(false),
             RuntimeType::RnFunction(_) => RuntimeType::boolean(false),
-        }
-    }
-    pub fn or(&self, _operand: RuntimeType) -> RuntimeType {
-        match _operand {
-            _ => RuntimeType::boolean(true),
-        }
-    }
-    pub fn and(&self, _operand: RuntimeType) -> RuntimeType {
-        match _operand {
-            RuntimeType::Nothing(_) => RuntimeType::boolean(false),
-            _ => RuntimeType::boolean(true),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVector(_) => RuntimeType::boolean(false),
+            RuntimeType::RnObject(_) => RuntimeType::boolean(false),
+            RuntimeType::RnStruct(_) => RuntimeType::boolean(false),
+            RuntimeType::RnInstance(_) => RuntimeType::boolean(false),
+            RuntimeType::RnEnum(_) => RuntimeType::boolean(false),
+            RuntimeType::RnVariant(_) => RuntimeType::boolean(false),
         }
     }
 }
@@ -0,0 +1,49 @@
+use crate::ast::identifier::Identifier;
+
+use super::function::RnFunction;
+
+// value bound to the name of a struct declaration
+#[derive(Debug, Clone)]
+pub struct RnStruct {
+    pub name: String,
+    // fields keep their optional type annotation
+    pub fields: Vec<Identifier>,
+    pub methods: Vec<RnFunction>,
+}
+
+impl RnStruct {
+    pub fn new(name: String, fields: Vec<Identifier>) -> RnStruct {
+        RnStruct {
+            name,
+            fields,
+            methods: vec![],
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        format!("struct {}", self.name)
+    }
+
+    pub fn to_boolean(&self) -> bool {
+        true
+    }
+
+    pub fn method(&self, name: &str) -> Option<&RnFunction> {
+        self.methods
+            .iter()
+            .find(|method| method.identifier.to_string() == name)
+    }
+
+    // a method defined again replaces the previous one
+    pub fn add_method(&mut self, method: RnFunction) {
+        let name = method.identifier.to_string();
+        match self
+            .methods
+            .iter_mut()
+            .find(|defined| defined.identifier.to_string() == name)
+        {
+            Some(defined) => *defined = method,
+            None => self.methods.push(method),
+        }
+    }
+}
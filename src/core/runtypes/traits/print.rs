@@ -1,5 +1,3 @@
-use crate::runtime::ScopesStack;
-
 pub trait Print {
-    fn print(&self, scopes: &ScopesStack) -> String;
+    fn print(&self) -> String;
 }
@@ -0,0 +1,108 @@
+use std::rc::Rc;
+
+use super::{enumeration::RnEnum, object::RnObject, RuntimeType};
+
+// value created by State.Idle or State.Running(3)
+#[derive(Debug, Clone)]
+pub struct RnVariant {
+    pub definition: Rc<RnEnum>,
+    pub name: String,
+    // payload named after the fields of the variant
+    pub fields: RnObject,
+}
+
+impl RnVariant {
+    pub fn new(
+        definition: Rc<RnEnum>,
+        name: String,
+        fields: Vec<(String, RuntimeType)>,
+    ) -> RnVariant {
+        RnVariant {
+            definition,
+            name,
+            fields: RnObject::new(fields),
+        }
+    }
+
+    pub fn enum_name(&self) -> &str {
+        &self.definition.name
+    }
+
+    // State.Idle | State.Running(3)
+    pub fn to_string(&self) -> String {
+        if self.fields.properties.is_empty() {
+            return format!("{}.{}", self.enum_name(), self.name);
+        }
+
+        let values: Vec<String> = self
+            .fields
+            .properties
+            .iter()
+            .map(|(_, value)| match value {
+                RuntimeType::RnString(s) => format!("\"{}\"", s.to_string()),
+                value => value.to_string(),
+            })
+            .collect();
+        format!("{}.{}({})", self.enum_name(), self.name, values.join(", "))
+    }
+
+    pub fn to_boolean(&self) -> bool {
+        true
+    }
+
+    // variants of different enums are never equal
+    fn equals(&self, other: &RnVariant) -> bool {
+        self.enum_name() == other.enum_name()
+            && self.name == other.name
+            && self.fields.equals(&other.fields)
+    }
+}
+
+// implement arithmetics
+impl RnVariant {
+    pub fn add(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn substract(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn mulitply(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn int_divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn modulo(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn power(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn greater_than(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::boolean(false)
+    }
+    pub fn less_than(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::boolean(false)
+    }
+    pub fn greater_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
+        self.equal(operand)
+    }
+    pub fn less_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
+        self.equal(operand)
+    }
+    pub fn not_equal(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::RnVariant(v) => RuntimeType::boolean(!self.equals(&v)),
+            _ => RuntimeType::boolean(true),
+        }
+    }
+    pub fn equal(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::RnVariant(v) => RuntimeType::boolean(self.equals(&v)),
+            _ => RuntimeType::boolean(false),
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use super::RuntimeType;
+use super::{traits::arithmetic::Arithmetic, RuntimeType};
 
 #[derive(Debug, Clone)]
 pub struct RnVector {
-    elements: Vec<RuntimeType>,
+    pub elements: Vec<RuntimeType>,
 }
 
 impl RnVector {
@@ -11,10 +11,119 @@ impl RnVector {
     }
 
     pub fn to_string(&self) -> String {
-        self.val.to_string()
+        let elements: Vec<String> = self
+            .elements
+            .iter()
+            .map(|element| match element {
+                // quote strings to tell apart ["1"] from [1]
+                RuntimeType::RnString(s) => format!("\"{}\"", s.to_string()),
+                _ => element.to_string(),
+            })
+            .collect();
+        format!("[{}]", elements.join(", "))
     }
 
     pub fn to_boolean(&self) -> bool {
-        self.val
+        self.elements.len() > 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&RuntimeType> {
+        self.elements.get(index)
+    }
+
+    pub fn set(&mut self, index: usize, value: RuntimeType) -> bool {
+        if let Some(element) = self.elements.get_mut(index) {
+            *element = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    // element-wise equality, elements are compared with
+    // their own '==' arithmetic
+    fn equals(&self, other: &RnVector) -> bool {
+        if self.elements.len() != other.elements.len() {
+            return false;
+        }
+
+        self.elements
+            .iter()
+            .zip(other.elements.iter())
+            .all(|(a, b)| match a.arithmetic("==", b.clone()) {
+                Ok(result) => result.to_boolean(),
+                Err(_) => false,
+            })
+    }
+}
+
+// implement arithmetics
+impl RnVector {
+    pub fn add(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::Nothing(_) => RuntimeType::nothing(),
+            RuntimeType::RnString(_) => RuntimeType::nothing(),
+            RuntimeType::RnBoolean(_) => RuntimeType::nothing(),
+            RuntimeType::RnNumber(_) => RuntimeType::nothing(),
+            RuntimeType::RnFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnNativeFunction(_) => RuntimeType::nothing(),
+            RuntimeType::RnObject(_) => RuntimeType::nothing(),
+            RuntimeType::RnStruct(_) => RuntimeType::nothing(),
+            RuntimeType::RnInstance(_) => RuntimeType::nothing(),
+            RuntimeType::RnEnum(_) => RuntimeType::nothing(),
+            RuntimeType::RnVariant(_) => RuntimeType::nothing(),
+            RuntimeType::RnVector(v) => {
+                // [1, 2] + [3] -> [1, 2, 3]
+                let mut elements = self.elements.clone();
+                elements.extend(v.elements);
+                RuntimeType::vector(elements)
+            }
+        }
+    }
+    pub fn substract(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn mulitply(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn int_divide(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn modulo(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn power(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::nothing()
+    }
+    pub fn greater_than(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::boolean(false)
+    }
+    pub fn less_than(&self, _operand: RuntimeType) -> RuntimeType {
+        RuntimeType::boolean(false)
+    }
+    pub fn greater_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
+        self.equal(operand)
+    }
+    pub fn less_than_or_equal(&self, operand: RuntimeType) -> RuntimeType {
+        self.equal(operand)
+    }
+    pub fn not_equal(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::RnVector(v) => RuntimeType::boolean(!self.equals(&v)),
+            _ => RuntimeType::boolean(true),
+        }
+    }
+    pub fn equal(&self, operand: RuntimeType) -> RuntimeType {
+        match operand {
+            RuntimeType::RnVector(v) => RuntimeType::boolean(self.equals(&v)),
+            _ => RuntimeType::boolean(false),
+        }
     }
 }
@@ -0,0 +1,98 @@
+use std::{fmt, sync::Arc};
+
+// source of a module, shared by the spans of its tokens and nodes
+// so diagnostics can show the offending line, an Arc keeps errors
+// Send and Sync
+#[derive(Debug, PartialEq)]
+pub struct SourceFile {
+    pub name: String,
+    pub source: String,
+}
+
+impl SourceFile {
+    pub fn new(name: &str, source: String) -> Arc<SourceFile> {
+        Arc::new(SourceFile {
+            name: name.to_string(),
+            source,
+        })
+    }
+}
+
+// byte range of a token or node in its module source,
+// line and col (in chars) are where the range starts
+#[derive(Clone)]
+pub struct Span {
+    pub file: Arc<SourceFile>,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl Span {
+    pub fn new(file: Arc<SourceFile>, start: usize, end: usize, line: usize, col: usize) -> Span {
+        Span {
+            file,
+            start,
+            end,
+            line,
+            col,
+        }
+    }
+
+    // from the start of this span to the end of the other one
+    pub fn to(&self, other: &Span) -> Span {
+        Span {
+            file: self.file.clone(),
+            start: self.start,
+            end: other.end.max(self.end),
+            line: self.line,
+            col: self.col,
+        }
+    }
+
+    // source line where the span starts
+    pub fn source_line(&self) -> &str {
+        self.file.source.lines().nth(self.line - 1).unwrap_or("")
+    }
+
+    // carets under the span, cut at the end of its first line
+    pub fn underline(&self) -> String {
+        let line = self.source_line();
+        let span_text = self
+            .file
+            .source
+            .get(self.start..self.end)
+            .unwrap_or("")
+            .lines()
+            .next()
+            .unwrap_or("");
+        let width = span_text.chars().count().max(1);
+        let padding: String = line
+            .chars()
+            .take(self.col - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        format!("{padding}{}", "^".repeat(width))
+    }
+}
+
+impl PartialEq for Span {
+    fn eq(&self, other: &Self) -> bool {
+        self.file.name == other.file.name && self.start == other.start && self.end == other.end
+    }
+}
+
+// file:line:col as shown by editors
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.name, self.line, self.col)
+    }
+}
+
+// the whole module source would be printed otherwise
+impl fmt::Debug for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}..{})", self, self.start, self.end)
+    }
+}
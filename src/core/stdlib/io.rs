@@ -0,0 +1,44 @@
+use std::{fs, io};
+
+use crate::core::{
+    error::ErrorType,
+    runtypes::{RnNativeFunction, RuntimeType},
+};
+
+use super::string_arg;
+
+pub fn functions() -> Vec<RnNativeFunction> {
+    vec![
+        RnNativeFunction::new("read_input", Some(0), read_input),
+        RnNativeFunction::new("read_file", Some(1), read_file),
+        RnNativeFunction::new("write_file", Some(2), write_file),
+    ]
+}
+
+// read_input() -> "line typed by the user"
+fn read_input(_args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(_) => Ok(RuntimeType::string(input.trim_end_matches(&['\r', '\n'][..]).to_string())),
+        Err(e) => Err((ErrorType::IoError, format!("Cannot read input: {e}"))),
+    }
+}
+
+// read_file("notes.txt") -> "file content"
+fn read_file(args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    let path = string_arg(args, 0)?;
+    match fs::read_to_string(&path) {
+        Ok(content) => Ok(RuntimeType::string(content)),
+        Err(e) => Err((ErrorType::IoError, format!("Cannot read '{path}': {e}"))),
+    }
+}
+
+// write_file("notes.txt", "content")
+fn write_file(args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    let path = string_arg(args, 0)?;
+    let content = string_arg(args, 1)?;
+    match fs::write(&path, content) {
+        Ok(_) => Ok(RuntimeType::nothing()),
+        Err(e) => Err((ErrorType::IoError, format!("Cannot write '{path}': {e}"))),
+    }
+}
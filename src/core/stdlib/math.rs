@@ -0,0 +1,91 @@
+use std::{cell::Cell, rc::Rc};
+
+use crate::core::{
+    error::ErrorType,
+    runtypes::{RnNativeFunction, RuntimeType},
+};
+
+use super::number_arg;
+
+// owned by each interpreter so seeding one does not
+// change the numbers of another
+pub type RandomState = Rc<Cell<u64>>;
+
+pub fn random_state() -> RandomState {
+    Rc::new(Cell::new(initial_seed()))
+}
+
+pub fn functions(state: &RandomState) -> Vec<RnNativeFunction> {
+    let random_state = state.clone();
+    let seed_state = state.clone();
+    vec![
+        RnNativeFunction::new("floor", Some(1), floor),
+        RnNativeFunction::new("sqrt", Some(1), sqrt),
+        RnNativeFunction::new("pow", Some(2), pow),
+        RnNativeFunction::new("random", Some(0), move |_| random(&random_state)),
+        RnNativeFunction::new("seed", Some(1), move |args| seed(&seed_state, args)),
+    ]
+}
+
+fn floor(args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    Ok(RuntimeType::number(number_arg(args, 0)?.floor()))
+}
+
+fn sqrt(args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    let number = number_arg(args, 0)?;
+    if number < 0.0 {
+        return Err((
+            ErrorType::RangeError,
+            format!("Cannot get the square root of the negative number {number}"),
+        ));
+    }
+    Ok(RuntimeType::number(number.sqrt()))
+}
+
+fn pow(args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    let base = number_arg(args, 0)?;
+    let exponent = number_arg(args, 1)?;
+    Ok(RuntimeType::number(base.powf(exponent)))
+}
+
+// random() -> number between 0 (included) and 1 (excluded)
+fn random(state: &RandomState) -> Result<RuntimeType, (ErrorType, String)> {
+    // xorshift64*
+    let mut x = state.get();
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    state.set(x);
+    let value = x.wrapping_mul(0x2545F4914F6CDD1D) >> 11; // keep 53 bits
+
+    Ok(RuntimeType::number(value as f64 / (1u64 << 53) as f64))
+}
+
+// seed(42) makes the following random() calls reproducible
+fn seed(state: &RandomState, args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    let seed = number_arg(args, 0)?;
+    state.set(non_zero_state(seed.to_bits()));
+    Ok(RuntimeType::nothing())
+}
+
+fn initial_seed() -> u64 {
+    if cfg!(target_arch = "wasm32") {
+        // system time is not available
+        non_zero_state(0)
+    } else {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or(0);
+        non_zero_state(nanos)
+    }
+}
+
+// xorshift gets stuck on a zero state
+fn non_zero_state(seed: u64) -> u64 {
+    if seed == 0 {
+        0x9E3779B97F4A7C15
+    } else {
+        seed
+    }
+}
@@ -0,0 +1,68 @@
+mod io;
+mod math;
+mod string;
+
+use std::collections::HashMap;
+
+use crate::core::{error::ErrorType, runtypes::RuntimeType};
+
+pub use math::{random_state, RandomState};
+
+// import std/<name> -> members of the native module
+pub fn std_module(name: &str, random: &RandomState) -> Option<HashMap<String, RuntimeType>> {
+    let functions = match name {
+        "io" => io::functions(),
+        "math" => math::functions(random),
+        "string" => string::functions(),
+        _ => return None,
+    };
+
+    Some(
+        functions
+            .into_iter()
+            .map(|function| {
+                (
+                    function.name.clone(),
+                    RuntimeType::RnNativeFunction(function),
+                )
+            })
+            .collect(),
+    )
+}
+
+// arguments helpers for native functions
+pub fn string_arg(args: &[RuntimeType], index: usize) -> Result<String, (ErrorType, String)> {
+    match args.get(index) {
+        Some(RuntimeType::RnString(s)) => Ok(s.to_string()),
+        Some(value) => Err((
+            ErrorType::TypeError,
+            format!(
+                "Expected a string as argument {} but got '{}'",
+                index + 1,
+                value.type_name()
+            ),
+        )),
+        None => Err((
+            ErrorType::TypeError,
+            format!("Expected a string as argument {}", index + 1),
+        )),
+    }
+}
+
+pub fn number_arg(args: &[RuntimeType], index: usize) -> Result<f64, (ErrorType, String)> {
+    match args.get(index) {
+        Some(RuntimeType::RnNumber(n)) => Ok(n.val),
+        Some(value) => Err((
+            ErrorType::TypeError,
+            format!(
+                "Expected a number as argument {} but got '{}'",
+                index + 1,
+                value.type_name()
+            ),
+        )),
+        None => Err((
+            ErrorType::TypeError,
+            format!("Expected a number as argument {}", index + 1),
+        )),
+    }
+}
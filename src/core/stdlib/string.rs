@@ -0,0 +1,62 @@
+use crate::core::{
+    error::ErrorType,
+    runtypes::{RnNativeFunction, RuntimeType},
+};
+
+use super::string_arg;
+
+pub fn functions() -> Vec<RnNativeFunction> {
+    vec![
+        RnNativeFunction::new("split", Some(2), split),
+        RnNativeFunction::new("trim", Some(1), trim),
+        RnNativeFunction::new("replace", Some(3), replace),
+        RnNativeFunction::new("len", Some(1), len),
+    ]
+}
+
+// split("a,b", ",") -> ["a", "b"]
+fn split(args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    let string = string_arg(args, 0)?;
+    let separator = string_arg(args, 1)?;
+
+    let parts: Vec<RuntimeType> = if separator.len() < 1 {
+        // split("abc", "") -> ["a", "b", "c"]
+        string
+            .chars()
+            .map(|c| RuntimeType::string(c.to_string()))
+            .collect()
+    } else {
+        string
+            .split(separator.as_str())
+            .map(|part| RuntimeType::string(part.to_string()))
+            .collect()
+    };
+    Ok(RuntimeType::vector(parts))
+}
+
+fn trim(args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    let string = string_arg(args, 0)?;
+    Ok(RuntimeType::string(string.trim().to_string()))
+}
+
+fn replace(args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    let string = string_arg(args, 0)?;
+    let from = string_arg(args, 1)?;
+    let to = string_arg(args, 2)?;
+    Ok(RuntimeType::string(string.replace(&from, &to)))
+}
+
+// len("ego") -> 3 | len([1, 2]) -> 2
+fn len(args: &[RuntimeType]) -> Result<RuntimeType, (ErrorType, String)> {
+    match &args[0] {
+        RuntimeType::RnString(s) => Ok(RuntimeType::number(s.to_string().chars().count() as f64)),
+        RuntimeType::RnVector(v) => Ok(RuntimeType::number(v.len() as f64)),
+        value => Err((
+            ErrorType::TypeError,
+            format!(
+                "Expected a string or vector as argument 1 but got '{}'",
+                value.type_name()
+            ),
+        )),
+    }
+}
@@ -0,0 +1,114 @@
+mod value;
+
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    ast::{lex, module::ModuleAst, Module},
+    checker::Checker,
+    core::{
+        error::EgoError,
+        runtypes::{traits::print::Print, RuntimeType},
+    },
+    runtime::Interpreter,
+};
+
+pub use self::value::{Definition, Function, Instance, Value, Variant};
+
+const ENGINE_MODULE: &str = "engine";
+
+// runs ego code from rust applications. Globals are kept
+// between evaluations and print(...) is captured instead
+// of being written to stdout
+pub struct Engine {
+    interpreter: Interpreter,
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl Engine {
+    pub fn new() -> Engine {
+        let output = Rc::new(RefCell::new(vec![]));
+        let mut interpreter = Interpreter::new(ModuleAst::new(ENGINE_MODULE));
+
+        let print_output = output.clone();
+        interpreter.register_native("print", None, move |args| {
+            let values: Vec<String> = args.iter().map(|arg| arg.print()).collect();
+            print_output.borrow_mut().push(values.join(" "));
+            Ok(RuntimeType::nothing())
+        });
+
+        Engine {
+            interpreter,
+            output,
+        }
+    }
+
+    // returns the value of the last expression statement
+    pub fn eval(&mut self, source: &str) -> Result<Value, EgoError> {
+        let tokens = lex(source.to_string(), ENGINE_MODULE)?;
+        let mut module = Module::new(ENGINE_MODULE.to_string(), tokens);
+        let ast = module.parse()?;
+        let value = self.interpreter.eval(ast)?;
+        Ok(Value::from_runtime(value))
+    }
+
+    // errors found without running the code, type errors
+    // are only looked for when there are no syntax errors
+    pub fn check(&self, source: &str) -> Vec<EgoError> {
+        let tokens = match lex(source.to_string(), ENGINE_MODULE) {
+            Ok(tokens) => tokens,
+            Err(e) => return vec![e],
+        };
+        let (ast, errors) = Module::new(ENGINE_MODULE.to_string(), tokens).parse_recovering();
+        if errors.is_empty() {
+            Checker::check(&ast)
+        } else {
+            errors
+        }
+    }
+
+    pub fn call(&mut self, fn_name: &str, args: Vec<Value>) -> Result<Value, EgoError> {
+        let args = args.into_iter().map(Value::into_runtime).collect();
+        let value = self.interpreter.call(fn_name, args)?;
+        Ok(Value::from_runtime(value))
+    }
+
+    pub fn get_global(&self, name: &str) -> Option<Value> {
+        self.interpreter.get_global(name).map(Value::from_runtime)
+    }
+
+    // defines the global or replaces its current value
+    pub fn set_global(&mut self, name: &str, value: Value) {
+        self.interpreter
+            .set_global(name.to_string(), value.into_runtime());
+    }
+
+    // makes a rust function callable from ego code, errors
+    // returned by the handler are raised on the call site
+    pub fn register_native<F>(&mut self, name: &str, arity: Option<usize>, handler: F)
+    where
+        F: Fn(&[Value]) -> Result<Value, EgoError> + 'static,
+    {
+        self.interpreter.register_native(name, arity, move |args| {
+            let args: Vec<Value> = args.iter().cloned().map(Value::from_runtime).collect();
+            handler(&args)
+                .map(Value::into_runtime)
+                .map_err(|e| (e.error_type, e.message))
+        });
+    }
+
+    // functions provided by the host, print(...) and type(...) included
+    pub fn natives(&self) -> Vec<String> {
+        self.interpreter.native_names()
+    }
+
+    // lines printed since the last call
+    pub fn take_output(&mut self) -> Vec<String> {
+        self.output.borrow_mut().drain(..).collect()
+    }
+}
+
+impl Default for Engine {
+    fn default() -> Engine {
+        Engine::new()
+    }
+}
@@ -0,0 +1,212 @@
+use std::{fmt, rc::Rc};
+
+use crate::core::runtypes::{RnEnum, RnStruct, RuntimeType};
+
+// ego values as seen from rust
+#[derive(Debug, Clone)]
+pub enum Value {
+    Nothing,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Vector(Vec<Value>),
+    Object(Vec<(String, Value)>),
+    Function(Function),
+    Struct(Definition),
+    Enum(Definition),
+    Instance(Instance),
+    Variant(Variant),
+}
+
+// functions are kept opaque so they can only be
+// handed back to the engine that created them
+#[derive(Debug, Clone)]
+pub struct Function {
+    name: String,
+    runtime: RuntimeType,
+}
+
+impl Function {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+// struct and enum definitions are opaque like functions,
+// their methods and variants stay in the engine
+#[derive(Debug, Clone)]
+pub struct Definition {
+    name: String,
+    runtime: RuntimeType,
+}
+
+impl Definition {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+// instances keep their struct so they are handed
+// back with its methods
+#[derive(Debug, Clone)]
+pub struct Instance {
+    definition: Rc<RnStruct>,
+    fields: Vec<(String, Value)>,
+}
+
+impl Instance {
+    pub fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    pub fn fields(&self) -> &[(String, Value)] {
+        &self.fields
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Variant {
+    definition: Rc<RnEnum>,
+    name: String,
+    fields: Vec<(String, Value)>,
+}
+
+impl Variant {
+    pub fn enum_name(&self) -> &str {
+        &self.definition.name
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn fields(&self) -> &[(String, Value)] {
+        &self.fields
+    }
+}
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Nothing => "nothing",
+            Value::Boolean(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Vector(_) => "vector",
+            Value::Object(_) => "object",
+            Value::Function(_) => "function",
+            Value::Struct(_) => "struct",
+            Value::Enum(_) => "enum",
+            Value::Instance(_) => "instance",
+            Value::Variant(_) => "variant",
+        }
+    }
+
+    pub(crate) fn from_runtime(value: RuntimeType) -> Value {
+        match value {
+            RuntimeType::Nothing(_) => Value::Nothing,
+            RuntimeType::RnBoolean(b) => Value::Boolean(b.to_boolean()),
+            RuntimeType::RnNumber(n) => Value::Number(n.val),
+            RuntimeType::RnString(s) => Value::String(s.to_string()),
+            RuntimeType::RnVector(v) => {
+                Value::Vector(v.elements.into_iter().map(Value::from_runtime).collect())
+            }
+            RuntimeType::RnObject(o) => Value::Object(fields_from_runtime(o.properties)),
+            RuntimeType::RnInstance(instance) => Value::Instance(Instance {
+                definition: instance.definition,
+                fields: fields_from_runtime(instance.fields.properties),
+            }),
+            RuntimeType::RnVariant(variant) => Value::Variant(Variant {
+                definition: variant.definition,
+                name: variant.name,
+                fields: fields_from_runtime(variant.fields.properties),
+            }),
+            RuntimeType::RnStruct(ref structure) => Value::Struct(Definition {
+                name: structure.name.clone(),
+                runtime: value,
+            }),
+            RuntimeType::RnEnum(ref enumeration) => Value::Enum(Definition {
+                name: enumeration.name.clone(),
+                runtime: value,
+            }),
+            RuntimeType::RnFunction(_) | RuntimeType::RnNativeFunction(_) => {
+                Value::Function(Function {
+                    name: value.to_string(),
+                    runtime: value,
+                })
+            }
+        }
+    }
+
+    pub(crate) fn into_runtime(self) -> RuntimeType {
+        match self {
+            Value::Nothing => RuntimeType::nothing(),
+            Value::Boolean(b) => RuntimeType::boolean(b),
+            Value::Number(n) => RuntimeType::number(n),
+            Value::String(s) => RuntimeType::string(s),
+            Value::Vector(v) => {
+                RuntimeType::vector(v.into_iter().map(Value::into_runtime).collect())
+            }
+            Value::Object(o) => RuntimeType::object(fields_into_runtime(o)),
+            Value::Instance(i) => {
+                RuntimeType::instance(i.definition, fields_into_runtime(i.fields))
+            }
+            Value::Variant(v) => {
+                RuntimeType::variant(v.definition, v.name, fields_into_runtime(v.fields))
+            }
+            Value::Function(f) => f.runtime,
+            Value::Struct(d) | Value::Enum(d) => d.runtime,
+        }
+    }
+}
+
+fn fields_from_runtime(fields: Vec<(String, RuntimeType)>) -> Vec<(String, Value)> {
+    fields
+        .into_iter()
+        .map(|(key, value)| (key, Value::from_runtime(value)))
+        .collect()
+}
+
+fn fields_into_runtime(fields: Vec<(String, Value)>) -> Vec<(String, RuntimeType)> {
+    fields
+        .into_iter()
+        .map(|(key, value)| (key, value.into_runtime()))
+        .collect()
+}
+
+// same output as print(value) in ego
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.clone().into_runtime().to_string())
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Value {
+        Value::Boolean(value)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Value {
+        Value::Number(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Value {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        Value::String(value)
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(value: Vec<Value>) -> Value {
+        Value::Vector(value)
+    }
+}
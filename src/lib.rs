@@ -1,10 +1,15 @@
 mod ast;
-mod commands;
+mod checker;
 mod compiler;
 mod core;
+mod engine;
 mod runtime;
 mod wasm;
 
+pub use crate::core::error::{EgoError, ErrorType};
+pub use engine::{Definition, Engine, Function, Instance, Value, Variant};
+pub use runtime::live_scopes;
+
 use ast::{lex, Module};
 use compiler::Compiler;
 use wasm::run_ego;
@@ -15,9 +20,9 @@ pub fn exec_ego_code(code: String, vm: bool) -> Vec<String> {
     run_ego(code, vm)
 }
 
-pub fn gen_bytecode(code: String) -> Vec<u8> {
-    let tokens = lex(code);
+pub fn gen_bytecode(code: String) -> Result<Vec<u8>, EgoError> {
+    let tokens = lex(code, "unknown")?;
     let mut module = Module::new("unknown".to_string(), tokens);
-    let ast = module.parse();
+    let ast = module.parse()?;
     Compiler::gen_bytecode(ast)
 }
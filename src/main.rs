@@ -1,4 +1,5 @@
 mod ast;
+mod checker;
 mod commands;
 mod compiler;
 mod core;
@@ -6,7 +7,21 @@ mod runtime;
 
 use commands::Command;
 
+// deep ego recursion needs more than the default main
+// thread stack before reaching the call depth limit
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
 fn main() {
-    let command = Command::parse();
-    command.exec();
+    let runner = std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(|| {
+            let command = Command::parse();
+            command.exec();
+        })
+        .expect("cannot start the ego thread");
+
+    // the panic message has already been printed
+    if runner.join().is_err() {
+        std::process::exit(101);
+    }
 }
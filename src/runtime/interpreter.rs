@@ -1,57 +1,265 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
 use crate::{
     ast::{
-        assignament_statement::{AssignamentNode, VarType}, binary_expression::BinaryExpression, block::Block, call_expression::CallExpression, if_statement::IfStatement, module::ModuleAst, while_statement::WhileStatement, AstNodeType, Expression
+        assignament_statement::{AssignamentNode, VarType},
+        block::Block,
+        else_statement::ElseBody,
+        enum_declaration::EnumVariant,
+        for_statement::ForStatement,
+        identifier::Identifier,
+        if_statement::IfStatement,
+        impl_declaration::ImplDeclaration,
+        import_statement::ImportStatement,
+        match_expression::{MatchArm, MatchExpression, Pattern},
+        member_assignament::MemberAssignament,
+        member_expression::MemberExpression,
+        module::ModuleAst,
+        range_expression::RangeExpression,
+        struct_expression::StructExpression,
+        while_statement::WhileStatement,
+        AstNodeType, Expression, Type,
     },
     core::{
-        error::{self, ErrorType},
-        handlers::{print_handler::print, type_handler::type_of},
-        runtypes::{traits::arithmetic::Arithmetic, RuntimeType},
+        error::{EgoError, ErrorType, Frame},
+        runtypes::{
+            traits::arithmetic::Arithmetic, NativeResult, RnEnum, RnFunction, RnIdentifier,
+            RuntimeType,
+        },
+        span::Span,
     },
     runtime::scope::ScopeInvoker,
 };
 
-use super::ScopesStack;
+use super::{
+    loader::{load_module, ModuleLoader},
+    NativeRegistry, ScopesStack,
+};
+
+// how a statement hands control back to the blocks around it
+enum ControlFlow {
+    Return(RuntimeType),
+    // optional label of the loop it leaves
+    Break(Option<String>, Span),
+    Continue(Option<String>, Span),
+}
+
+// name shown for functions created by fn (...) {...}
+const ANONYMOUS_FUNCTION: &str = "<anonymous>";
+// calls deeper than this are reported instead of
+// overflowing the native stack
+const MAX_CALL_DEPTH: usize = 1000;
 
 pub struct Interpreter {
     ast: ModuleAst,
     scopes: ScopesStack,
+    loader: Rc<RefCell<ModuleLoader>>,
+    natives: NativeRegistry,
 }
 
 impl Interpreter {
     pub fn new(ast: ModuleAst) -> Interpreter {
+        let loader = ModuleLoader::new(&ast.module_name);
+        Interpreter::with_context(ast, loader, NativeRegistry::with_builtins())
+    }
+
+    // modules imported from other modules share the loader
+    // so each one is executed just once, and the natives
+    // registered by the host
+    pub fn with_context(
+        ast: ModuleAst,
+        loader: Rc<RefCell<ModuleLoader>>,
+        natives: NativeRegistry,
+    ) -> Interpreter {
+        let mut scopes = ScopesStack::new(ScopeInvoker::Prelude);
+        for function in natives.functions() {
+            scopes.set_prelude_identifier(
+                function.name.clone(),
+                RuntimeType::RnNativeFunction(function.clone()),
+            );
+        }
+        scopes.push(ScopeInvoker::Module);
+
         Interpreter {
             ast,
-            scopes: ScopesStack::new(ScopeInvoker::Module),
+            scopes,
+            loader,
+            natives,
+        }
+    }
+
+    pub fn module_identifiers(&self) -> HashMap<String, RuntimeType> {
+        self.scopes.module_identifiers()
+    }
+
+    pub fn exec(&mut self, debug: bool) -> Result<(), EgoError> {
+        self.run(debug)?;
+        Ok(())
+    }
+
+    // scopes pushed by the failing node are dropped so the
+    // interpreter can keep being used after an error
+    fn run(&mut self, debug: bool) -> Result<RuntimeType, EgoError> {
+        let depth = self.scopes.depth();
+        let result = self.run_module(debug);
+        if result.is_err() {
+            self.scopes.truncate(depth);
         }
+        result
     }
 
-    pub fn exec(&mut self, debug: bool) {
+    fn run_module(&mut self, debug: bool) -> Result<RuntimeType, EgoError> {
         // hoisting
         let mut counter = 0;
         while counter < self.ast.children.len() {
-            hoist_node(&self.ast.children[counter], &mut self.scopes, ScopeInvoker::Module);
+            hoist_node(
+                &self.ast.children[counter],
+                &mut self.scopes,
+                ScopeInvoker::Module,
+            )?;
             counter += 1;
         }
         if debug {
             println!("\nScopes: \n---------------\n{:#?}", self.scopes);
         }
-    
+
         // execution
         let mut counter = 0;
+        let mut last_value = RuntimeType::nothing();
         while counter < self.ast.children.len() {
-            exec_node(&self.ast.children[counter], &mut self.scopes, ScopeInvoker::Module); 
+            match &self.ast.children[counter] {
+                // imports are only allowed at the module level
+                AstNodeType::ImportStatement(node) => {
+                    let node = node.clone();
+                    self.exec_import(&node)?;
+                }
+                AstNodeType::Expression(expr) => {
+                    last_value = calc_expression(expr, &mut self.scopes)?;
+                }
+                node => match exec_node(node, &mut self.scopes, ScopeInvoker::Module)? {
+                    Some(ControlFlow::Break(_, span)) | Some(ControlFlow::Continue(_, span)) => {
+                        return Err(outside_loop_error(span));
+                    }
+                    // module level returns are ignored
+                    Some(ControlFlow::Return(_)) | None => {}
+                },
+            }
             counter += 1;
         }
+        Ok(last_value)
+    }
+
+    fn exec_import(&mut self, node: &ImportStatement) -> Result<(), EgoError> {
+        let exports = load_module(&self.loader, &self.natives, &self.ast.module_name, node)?;
+
+        // import utils; -> utils.member
+        if node.members.len() < 1 {
+            let module_identifier = node.module.last().cloned().unwrap_or_default();
+            let mut properties: Vec<(String, RuntimeType)> = exports.into_iter().collect();
+            properties.sort_by(|a, b| a.0.cmp(&b.0));
+            return self
+                .scopes
+                .add_identifier(module_identifier, RuntimeType::object(properties))
+                .map_err(|e| e.with_span(node.span.clone()));
+        }
+
+        // import utils.[member, member]
+        for member in &node.members {
+            match exports.get(member) {
+                Some(value) => self
+                    .scopes
+                    .add_identifier(member.clone(), value.clone())
+                    .map_err(|e| e.with_span(node.span.clone()))?,
+                None => {
+                    return Err(EgoError::new(
+                        ErrorType::ImportError,
+                        format!(
+                            "Module '{}' has no member named '{member}'",
+                            node.module.join("/")
+                        )
+                        .as_str(),
+                        Some(node.span.clone()),
+                    ))
+                }
+            }
+        }
+        Ok(())
     }
 }
 
-fn hoist_node(node: &AstNodeType, scopes: &mut ScopesStack, invoker: ScopeInvoker) {
+// host api used by the engine, the cli binary
+// only runs whole modules through exec
+#[allow(dead_code)]
+impl Interpreter {
+    // makes a rust function callable from ego code
+    pub fn register_native<F>(&mut self, name: &str, arity: Option<usize>, handler: F)
+    where
+        F: Fn(&[RuntimeType]) -> NativeResult + 'static,
+    {
+        self.natives.register(name, arity, handler);
+        if let Some(function) = self.natives.get(name) {
+            self.scopes.set_prelude_identifier(
+                name.to_string(),
+                RuntimeType::RnNativeFunction(function.clone()),
+            );
+        }
+    }
+
+    // names of the registered natives, builtins included
+    pub fn native_names(&self) -> Vec<String> {
+        self.natives.names()
+    }
+
+    // module level identifiers, falling back to the prelude
+    pub fn get_global(&self, identifier: &str) -> Option<RuntimeType> {
+        self.scopes.find_identifier(identifier)
+    }
+
+    pub fn set_global(&mut self, identifier: String, value: RuntimeType) {
+        self.scopes.set_module_identifier(identifier, value);
+    }
+
+    // runs another module over the same scopes, so globals defined
+    // by previous evaluations are kept. Returns the value of the
+    // last expression statement
+    pub fn eval(&mut self, ast: ModuleAst) -> Result<RuntimeType, EgoError> {
+        self.ast = ast;
+        self.run(false)
+    }
+
+    // calls a function defined in the module scope or the prelude
+    pub fn call(
+        &mut self,
+        identifier: &str,
+        arguments: Vec<RuntimeType>,
+    ) -> Result<RuntimeType, EgoError> {
+        let function = self.get_global(identifier).ok_or_else(|| {
+            EgoError::new(
+                ErrorType::ReferenceError,
+                format!("Function '{identifier}' has not been defined").as_str(),
+                None,
+            )
+        })?;
+        let depth = self.scopes.depth();
+        let result = call_function(identifier, function, arguments, &mut self.scopes, None);
+        if result.is_err() {
+            self.scopes.truncate(depth);
+        }
+        result
+    }
+}
+
+fn hoist_node(
+    node: &AstNodeType,
+    scopes: &mut ScopesStack,
+    invoker: ScopeInvoker,
+) -> Result<(), EgoError> {
     match node {
         AstNodeType::Block(node) => {
             // block level hoisting
             let mut counter = 0;
             while counter < node.children.len() {
-                hoist_node(&node.children[counter], scopes, invoker);
+                hoist_node(&node.children[counter], scopes, invoker)?;
                 counter += 1;
             }
         }
@@ -62,8 +270,8 @@ fn hoist_node(node: &AstNodeType, scopes: &mut ScopesStack, invoker: ScopeInvoke
                 &AstNodeType::Block(node.body.clone()),
                 scopes,
                 ScopeInvoker::IfStatement,
-            );
-            scopes.pop();
+            )?;
+            scopes.pop()?;
         }
         AstNodeType::WhileStatement(node) => {
             // block level hoisting
@@ -72,8 +280,18 @@ fn hoist_node(node: &AstNodeType, scopes: &mut ScopesStack, invoker: ScopeInvoke
                 &AstNodeType::Block(node.body.clone()),
                 scopes,
                 ScopeInvoker::WhileStatement,
-            );
-            scopes.pop();
+            )?;
+            scopes.pop()?;
+        }
+        AstNodeType::ForStatement(node) => {
+            // block level hoisting
+            scopes.push(ScopeInvoker::ForStatement);
+            hoist_node(
+                &AstNodeType::Block(node.body.clone()),
+                scopes,
+                ScopeInvoker::ForStatement,
+            )?;
+            scopes.pop()?;
         }
         AstNodeType::FunctionDeclaration(node) => {
             // hoist current function
@@ -81,11 +299,14 @@ fn hoist_node(node: &AstNodeType, scopes: &mut ScopesStack, invoker: ScopeInvoke
             let rn_function = RuntimeType::function(
                 identifier.clone(),
                 node.parameters.clone(),
+                node.return_type,
                 node.body.clone(),
-                node.at,
-                node.line,
+                node.span.clone(),
+                scopes.capture(),
             );
-            scopes.add_identifier(identifier, rn_function);
+            scopes
+                .add_identifier(identifier, rn_function)
+                .map_err(|e| e.with_span(node.span.clone()))?;
 
             // hoist inside function body
             scopes.push(ScopeInvoker::Function);
@@ -93,320 +314,1103 @@ fn hoist_node(node: &AstNodeType, scopes: &mut ScopesStack, invoker: ScopeInvoke
                 &AstNodeType::Block(node.body.clone()),
                 scopes,
                 ScopeInvoker::Function,
-            );
-            scopes.pop();
+            )?;
+            scopes.pop()?;
+        }
+        AstNodeType::StructDeclaration(node) => {
+            let rn_struct =
+                RuntimeType::structure(node.identifier.name.clone(), node.fields.clone());
+            scopes
+                .add_identifier(node.identifier.name.clone(), rn_struct)
+                .map_err(|e| e.with_span(node.span.clone()))?;
+        }
+        AstNodeType::ImplDeclaration(node) => implement(node, scopes)?,
+        AstNodeType::EnumDeclaration(node) => {
+            let rn_enum =
+                RuntimeType::enumeration(node.identifier.name.clone(), node.variants.clone());
+            scopes
+                .add_identifier(node.identifier.name.clone(), rn_enum)
+                .map_err(|e| e.with_span(node.span.clone()))?;
         }
         _ => {}
     }
+    Ok(())
+}
+
+// adds the methods to the struct bound to the impl name,
+// instances created after this can call them
+fn implement(node: &ImplDeclaration, scopes: &mut ScopesStack) -> Result<(), EgoError> {
+    let name = &node.identifier.name;
+    let mut rn_struct = match scopes.get_identifier_value(name) {
+        Ok(RuntimeType::RnStruct(rn_struct)) => rn_struct,
+        Ok(value) => {
+            return Err(EgoError::new(
+                ErrorType::TypeError,
+                format!(
+                    "Cannot implement '{name}' because it is a value of type '{}' and not a struct",
+                    value.type_name()
+                )
+                .as_str(),
+                Some(node.identifier.span.clone()),
+            ))
+        }
+        Err(_) => {
+            return Err(EgoError::new(
+                ErrorType::ReferenceError,
+                format!("Struct '{name}' must be declared before its impl block").as_str(),
+                Some(node.identifier.span.clone()),
+            ))
+        }
+    };
+
+    for method in &node.methods {
+        rn_struct.add_method(RnFunction::new(
+            RnIdentifier::new(method.identifier.name.clone()),
+            method.parameters.clone(),
+            method.return_type,
+            method.body.clone(),
+            method.span.clone(),
+            scopes.capture(),
+        ));
+    }
+    scopes
+        .set_indentifier(name.clone(), RuntimeType::RnStruct(rn_struct))
+        .map_err(|e| e.with_span(node.span.clone()))
 }
 
 fn exec_node(
     node: &AstNodeType,
     scopes: &mut ScopesStack,
     invoker: ScopeInvoker,
-) -> Option<RuntimeType> {
-    match node {
-        AstNodeType::Block(node) => exec_block(node, scopes, invoker), 
-        AstNodeType::FunctionDeclaration(_node) => None, 
-        AstNodeType::IfStatement(node) => exec_if(node, scopes, invoker), 
-        AstNodeType::WhileStatement(node) => exec_while(node, scopes, invoker), 
-        AstNodeType::AssignamentStatement(node) => exec_assignament(node, scopes, invoker), 
-        AstNodeType::Expression(expr) => calc_expression(expr, scopes),
-        _ => None,
-    }
+) -> Result<Option<ControlFlow>, EgoError> {
+    // errors without a position get the one of the statement
+    let flow = match node {
+        AstNodeType::Block(node) => exec_block(node, scopes, invoker),
+        AstNodeType::FunctionDeclaration(_node) => Ok(None),
+        AstNodeType::IfStatement(node) => exec_if(node, scopes, invoker),
+        AstNodeType::WhileStatement(node) => exec_while(node, scopes, invoker),
+        AstNodeType::ForStatement(node) => exec_for(node, scopes, invoker),
+        AstNodeType::AssignamentStatement(node) => exec_assignament(node, scopes, invoker),
+        AstNodeType::MemberAssignament(node) => exec_member_assignament(node, scopes, invoker),
+        AstNodeType::ReturnStatement(node) => Ok(Some(ControlFlow::Return(calc_expression(
+            &node.value,
+            scopes,
+        )?))),
+        AstNodeType::BreakStatement(node) => Ok(Some(ControlFlow::Break(
+            node.label.as_ref().map(|label| label.name.clone()),
+            node.span.clone(),
+        ))),
+        AstNodeType::ContinueStatement(node) => Ok(Some(ControlFlow::Continue(
+            node.label.as_ref().map(|label| label.name.clone()),
+            node.span.clone(),
+        ))),
+        // arms of a match statement can return or leave a loop
+        AstNodeType::Expression(Expression::MatchExpression(node)) => {
+            exec_match(node, scopes, invoker)
+        }
+        // expression statements are evaluated for their side effects,
+        // only return statements hand a value back to the caller
+        AstNodeType::Expression(expr) => calc_expression(expr, scopes).map(|_| None),
+        _ => Ok(None),
+    };
+    flow.map_err(|e| e.with_span(node.span().clone()))
 }
 
 fn exec_block(
     node: &Block,
     scopes: &mut ScopesStack,
     invoker: ScopeInvoker,
-) -> Option<RuntimeType> {
-    let mut counter = 0;
-    let mut return_expr = None;
-    while counter < node.children.len() {
-        let children = &node.children[counter];
-        counter += 1;
-
-        match invoker {
-            ScopeInvoker::WhileStatement => match children {
-                AstNodeType::BreakStatement(_) => {
-                    return_expr = Some(RuntimeType::nothing());
-                }
-                AstNodeType::ReturnStatement(_) => {
-                    error::throw(
-                        ErrorType::SyntaxError,
-                        "Return statements are not valid inside while loop",
-                        Some(children.line()),
-                    );
-                    std::process::exit(1);
-                }
-                _ => {
-                    let exec_return = exec_node(children, scopes, invoker);
-                    if exec_return.is_some() {
-                        return_expr = exec_return;
-                        break;
-                    }
-                }
-            },
-            _ => {
-                if let AstNodeType::ReturnStatement(ret) = children {
-                    return_expr = match &ret.value {
-                        Expression::Number(v) => Some(RuntimeType::number(v.value)),
-                        Expression::StringLiteral(v) => {
-                            Some(RuntimeType::string(v.value.clone(), false))
-                        }
-                        Expression::Bool(v) => Some(RuntimeType::boolean(v.value)),
-                        Expression::Identifier(v) => Some(RuntimeType::identifier(v.name.clone())),
-                        Expression::Nothing(_) => Some(RuntimeType::nothing()),
-                        Expression::BinaryExpression(v) => calc_expression(
-                            &Expression::BinaryExpression(BinaryExpression::new(
-                                v.operator.clone(),
-                                v.left.clone(),
-                                v.right.clone(),
-                                v.at,
-                                v.line,
-                            )),
-                            scopes,
-                        ),
-                        Expression::CallExpression(v) => calc_expression(
-                            &Expression::CallExpression(CallExpression::new(
-                                v.identifier.clone(),
-                                v.arguments.clone(),
-                                v.at,
-                                v.line,
-                            )),
-                            scopes,
-                        ),
-                    };
-                    break;
-                } else {
-                    let exec_return = exec_node(children, scopes, invoker);
-                    if exec_return.is_some() {
-                        return_expr = exec_return;
-                        break;
-                    }
-                }
-            }
+) -> Result<Option<ControlFlow>, EgoError> {
+    define_declarations(node, scopes)?;
+
+    // return, break and continue skip the rest of the block
+    for children in &node.children {
+        let flow = exec_node(children, scopes, invoker)?;
+        if flow.is_some() {
+            return Ok(flow);
         }
     }
+    Ok(None)
+}
 
-    if let Some(return_value) = return_expr {
-        Some(return_value)
-    } else {
-        None
+// functions, structs and enums are defined when their block runs,
+// so they capture the scopes of that execution
+fn define_declarations(node: &Block, scopes: &mut ScopesStack) -> Result<(), EgoError> {
+    for children in &node.children {
+        match children {
+            AstNodeType::FunctionDeclaration(function) => {
+                let rn_function = RuntimeType::function(
+                    function.identifier.name.clone(),
+                    function.parameters.clone(),
+                    function.return_type,
+                    function.body.clone(),
+                    function.span.clone(),
+                    scopes.capture(),
+                );
+                scopes.set_local_identifier(function.identifier.name.clone(), rn_function);
+            }
+            AstNodeType::StructDeclaration(node) => {
+                let rn_struct =
+                    RuntimeType::structure(node.identifier.name.clone(), node.fields.clone());
+                scopes.set_local_identifier(node.identifier.name.clone(), rn_struct);
+            }
+            AstNodeType::ImplDeclaration(node) => implement(node, scopes)?,
+            AstNodeType::EnumDeclaration(node) => {
+                let rn_enum =
+                    RuntimeType::enumeration(node.identifier.name.clone(), node.variants.clone());
+                scopes.set_local_identifier(node.identifier.name.clone(), rn_enum);
+            }
+            _ => {}
+        }
     }
+    Ok(())
 }
 
 fn exec_assignament(
     node: &AssignamentNode,
     scopes: &mut ScopesStack,
     _invoker: ScopeInvoker,
-) -> Option<RuntimeType> {
-    let value_as_runtype = calc_expression(&node.init, scopes).unwrap_or_else(|| {
-        error::throw(
-            ErrorType::InterpretingError,
-            "This is a known possible issue. Please report on https://github.com/noreplydev/ego with your code",
-            Some(node.line),
-        );
-        std::process::exit(1);
-    });
+) -> Result<Option<ControlFlow>, EgoError> {
+    let value_as_runtype = calc_expression(&node.init, scopes)?;
     match node.var_type {
-        VarType::None => {
-            scopes.set_indentifier(node.identifier.name.clone(), value_as_runtype);
+        VarType::None => scopes.set_indentifier(node.identifier.name.clone(), value_as_runtype),
+        VarType::Let => scopes.add_identifier(node.identifier.name.clone(), value_as_runtype),
+        VarType::Const => scopes.add_constant(node.identifier.name.clone(), value_as_runtype),
+    }
+    .map_err(|e| e.with_span(node.span.clone()))?;
+    Ok(None)
+}
+
+fn exec_member_assignament(
+    node: &MemberAssignament,
+    scopes: &mut ScopesStack,
+    _invoker: ScopeInvoker,
+) -> Result<Option<ControlFlow>, EgoError> {
+    let value_as_runtype = calc_expression(&node.init, scopes)?;
+    assign_member(&node.target, value_as_runtype, scopes, node.span.clone())?;
+    Ok(None)
+}
+
+// values are not shared, so the mutated member is written back
+// through each container until reaching the root identifier
+fn assign_member(
+    target: &Expression,
+    value: RuntimeType,
+    scopes: &mut ScopesStack,
+    span: Span,
+) -> Result<(), EgoError> {
+    match target {
+        Expression::Identifier(v) => scopes
+            .set_indentifier(v.name.clone(), value)
+            .map_err(|e| e.with_span(span)),
+        Expression::IndexExpression(v) => {
+            let container = calc_expression(&v.object, scopes)?;
+            let index = calc_expression(&v.index, scopes)?;
+            let container = match container {
+                RuntimeType::RnVector(mut vector) => {
+                    let position = vector_index(&index, vector.len(), span.clone())?;
+                    vector.set(position, value);
+                    RuntimeType::RnVector(vector)
+                }
+                container => {
+                    return Err(EgoError::new(
+                        ErrorType::TypeError,
+                        format!(
+                            "Cannot assign by index to a value of type '{}'",
+                            container.type_name()
+                        )
+                        .as_str(),
+                        Some(span),
+                    ));
+                }
+            };
+            assign_member(&v.object, container, scopes, span)
         }
-        VarType::Const | VarType::Let => {
-            scopes.add_identifier(node.identifier.name.clone(), value_as_runtype);
+        Expression::MemberExpression(v) => {
+            let container = match calc_expression(&v.object, scopes)? {
+                RuntimeType::RnObject(mut object) => {
+                    object.set(v.property.name.clone(), value);
+                    RuntimeType::RnObject(object)
+                }
+                RuntimeType::RnInstance(mut instance) => {
+                    let field = match instance
+                        .definition
+                        .fields
+                        .iter()
+                        .find(|field| field.name == v.property.name)
+                    {
+                        Some(field) => field,
+                        None => return Err(unknown_field(instance.name(), &v.property.name, span)),
+                    };
+                    check_field(instance.name(), field, &value, &span)?;
+                    instance.fields.set(v.property.name.clone(), value);
+                    RuntimeType::RnInstance(instance)
+                }
+                container => {
+                    return Err(EgoError::new(
+                        ErrorType::TypeError,
+                        format!(
+                            "Cannot set property '{}' on a value of type '{}'",
+                            v.property.name,
+                            container.type_name()
+                        )
+                        .as_str(),
+                        Some(span),
+                    ));
+                }
+            };
+            assign_member(&v.object, container, scopes, span)
+        }
+        _ => Err(EgoError::new(
+            ErrorType::SyntaxError,
+            "Invalid left-hand side in member assignment",
+            Some(span),
+        )),
+    }
+}
+
+// checks that the runtime index is a whole number inside bounds
+fn vector_index(index: &RuntimeType, len: usize, span: Span) -> Result<usize, EgoError> {
+    let position = match index {
+        RuntimeType::RnNumber(n) if n.val >= 0.0 && n.val.fract() == 0.0 => n.val as usize,
+        _ => {
+            return Err(EgoError::new(
+                ErrorType::TypeError,
+                format!(
+                    "Index must be a positive whole number but got '{}'",
+                    index.to_string()
+                )
+                .as_str(),
+                Some(span),
+            ));
         }
+    };
+
+    if position >= len {
+        return Err(EgoError::new(
+            ErrorType::RangeError,
+            format!("Index {position} is out of bounds for a length of {len}").as_str(),
+            Some(span),
+        ));
     }
-    None
+    Ok(position)
 }
 
 fn exec_if(
     node: &IfStatement,
     scopes: &mut ScopesStack,
-    _invoker: ScopeInvoker,
-) -> Option<RuntimeType> {
-    let condition = calc_expression(&node.condition, scopes).unwrap_or_else(|| {
-        error::throw(
-            ErrorType::InterpretingError,
-            "This is a known possible issue. Please report on https://github.com/noreplydev/ego with your code",
-            Some(node.line),
-        );
-        std::process::exit(1);
-    });
+    invoker: ScopeInvoker,
+) -> Result<Option<ControlFlow>, EgoError> {
+    let condition = calc_expression(&node.condition, scopes)?;
+    let body = if condition.to_boolean() {
+        &node.body
+    } else {
+        match node.else_node.as_ref().map(|else_node| &else_node.body) {
+            Some(ElseBody::Block(body)) => body,
+            Some(ElseBody::If(node)) => return exec_if(node, scopes, invoker),
+            None => return Ok(None),
+        }
+    };
+
     scopes.push(ScopeInvoker::IfStatement);
-    let mut return_expr = None;
+    let flow = exec_node(
+        &AstNodeType::Block(body.clone()),
+        scopes,
+        ScopeInvoker::IfStatement,
+    );
+    scopes.pop()?;
+    flow
+}
 
-    if condition.to_boolean() {
-        return_expr = exec_node(
-            &AstNodeType::Block(node.body.clone()),
-            scopes,
-            ScopeInvoker::IfStatement,
-        )
-    } else if let Some(else_body) = &node.else_node {
-        return_expr = exec_node(
-            &AstNodeType::Block(else_body.body.clone()),
-            scopes,
-            ScopeInvoker::IfStatement,
+// the value of an if expression is the last
+// expression of the arm that runs
+fn calc_if(node: &IfStatement, scopes: &mut ScopesStack) -> Result<RuntimeType, EgoError> {
+    let condition = calc_expression(&node.condition, scopes)?;
+    let body = if condition.to_boolean() {
+        &node.body
+    } else {
+        match node.else_node.as_ref().map(|else_node| &else_node.body) {
+            Some(ElseBody::Block(body)) => body,
+            Some(ElseBody::If(node)) => return calc_if(node, scopes),
+            None => return Ok(RuntimeType::nothing()),
+        }
+    };
+
+    scopes.push(ScopeInvoker::IfStatement);
+    let value = calc_block(body, scopes);
+    scopes.pop()?;
+    value
+}
+
+fn calc_block(node: &Block, scopes: &mut ScopesStack) -> Result<RuntimeType, EgoError> {
+    define_declarations(node, scopes)?;
+
+    // the parser rejects jumps that would leave the expression
+    let mut value = RuntimeType::nothing();
+    for children in &node.children {
+        value = match children {
+            AstNodeType::Expression(expr) => calc_expression(expr, scopes)?,
+            AstNodeType::IfStatement(node) => calc_if(node, scopes)?,
+            node => {
+                exec_node(node, scopes, ScopeInvoker::IfStatement)?;
+                RuntimeType::nothing()
+            }
+        };
+    }
+    Ok(value)
+}
+
+fn exec_match(
+    node: &MatchExpression,
+    scopes: &mut ScopesStack,
+    invoker: ScopeInvoker,
+) -> Result<Option<ControlFlow>, EgoError> {
+    let mut bindings = vec![];
+    let arm = find_arm(node, &mut bindings, scopes)?;
+
+    scopes.push(ScopeInvoker::MatchArm);
+    let flow = bind_pattern(arm, bindings, scopes)
+        .and_then(|_| exec_node(&AstNodeType::Block(arm.body.clone()), scopes, invoker));
+    scopes.pop()?;
+    flow
+}
+
+// the value of a match expression is the last
+// expression of the arm that runs
+fn calc_match(node: &MatchExpression, scopes: &mut ScopesStack) -> Result<RuntimeType, EgoError> {
+    let mut bindings = vec![];
+    let arm = find_arm(node, &mut bindings, scopes)?;
+
+    scopes.push(ScopeInvoker::MatchArm);
+    let value = bind_pattern(arm, bindings, scopes).and_then(|_| calc_block(&arm.body, scopes));
+    scopes.pop()?;
+    value
+}
+
+// arms are tried in order, the first one whose pattern matches
+// runs with the values bound by that pattern
+fn find_arm<'a>(
+    node: &'a MatchExpression,
+    bindings: &mut Vec<(String, RuntimeType)>,
+    scopes: &mut ScopesStack,
+) -> Result<&'a MatchArm, EgoError> {
+    let subject = calc_expression(&node.subject, scopes)?;
+    for arm in &node.arms {
+        bindings.clear();
+        if matches_pattern(&arm.pattern, &subject, bindings, scopes)? {
+            return Ok(arm);
+        }
+    }
+
+    Err(EgoError::new(
+        ErrorType::MatchError,
+        format!("No arm matches the value '{}'", subject.to_string()).as_str(),
+        Some(node.span.clone()),
+    ))
+}
+
+fn bind_pattern(
+    arm: &MatchArm,
+    bindings: Vec<(String, RuntimeType)>,
+    scopes: &mut ScopesStack,
+) -> Result<(), EgoError> {
+    for (name, value) in bindings {
+        scopes
+            .add_identifier(name, value)
+            .map_err(|e| e.with_span(arm.pattern.span().clone()))?;
+    }
+    Ok(())
+}
+
+fn matches_pattern(
+    pattern: &Pattern,
+    value: &RuntimeType,
+    bindings: &mut Vec<(String, RuntimeType)>,
+    scopes: &mut ScopesStack,
+) -> Result<bool, EgoError> {
+    match pattern {
+        Pattern::Wildcard(_) => Ok(true),
+        Pattern::Binding(identifier) => {
+            bindings.push((identifier.name.clone(), value.clone()));
+            Ok(true)
+        }
+        Pattern::Literal(literal) => {
+            let literal = calc_expression(literal, scopes)?;
+            Ok(value
+                .arithmetic("==", literal)
+                .is_ok_and(|equal| equal.to_boolean()))
+        }
+        Pattern::Variant(pattern) => {
+            let definition = match scopes
+                .get_identifier_value(&pattern.enumeration.name)
+                .map_err(|e| e.with_span(pattern.enumeration.span.clone()))?
+            {
+                RuntimeType::RnEnum(definition) => definition,
+                value => {
+                    return Err(EgoError::new(
+                        ErrorType::TypeError,
+                        format!(
+                            "Cannot match a variant of '{}' because it is a value of type '{}' and not an enum",
+                            pattern.enumeration.name,
+                            value.type_name()
+                        )
+                        .as_str(),
+                        Some(pattern.enumeration.span.clone()),
+                    ))
+                }
+            };
+            let variant = enum_variant(&definition, &pattern.variant.name, &pattern.span)?;
+
+            // State.Running matches any payload
+            if !pattern.patterns.is_empty() && pattern.patterns.len() != variant.fields.len() {
+                return Err(EgoError::new(
+                    ErrorType::TypeError,
+                    format!(
+                        "Variant '{}.{}' has {} but the pattern matches {}",
+                        definition.name,
+                        variant.identifier.name,
+                        plural(variant.fields.len(), "field"),
+                        pattern.patterns.len()
+                    )
+                    .as_str(),
+                    Some(pattern.span.clone()),
+                ));
+            }
+
+            let value = match value {
+                RuntimeType::RnVariant(value)
+                    if value.enum_name() == definition.name
+                        && value.name == variant.identifier.name =>
+                {
+                    value
+                }
+                _ => return Ok(false),
+            };
+            for (pattern, (_, field)) in pattern.patterns.iter().zip(&value.fields.properties) {
+                if !matches_pattern(pattern, field, bindings, scopes)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+    }
+}
+
+// State.Running(3)
+fn calc_variant(
+    definition: RnEnum,
+    node: &MemberExpression,
+    arguments: Vec<RuntimeType>,
+    span: &Span,
+) -> Result<RuntimeType, EgoError> {
+    let variant = enum_variant(&definition, &node.property.name, &node.span)?;
+    let name = format!("{}.{}", definition.name, variant.identifier.name);
+    if arguments.len() != variant.fields.len() {
+        return Err(EgoError::new(
+            ErrorType::TypeError,
+            format!(
+                "Variant '{name}' expects {} but got {}",
+                plural(variant.fields.len(), "value"),
+                arguments.len()
+            )
+            .as_str(),
+            Some(span.clone()),
+        ));
+    }
+
+    let mut fields = vec![];
+    for (field, value) in variant.fields.iter().zip(arguments) {
+        check_field(&name, field, &value, span)?;
+        fields.push((field.name.clone(), value));
+    }
+    let variant = variant.identifier.name.clone();
+    Ok(RuntimeType::variant(Rc::new(definition), variant, fields))
+}
+
+fn enum_variant<'a>(
+    definition: &'a RnEnum,
+    name: &str,
+    span: &Span,
+) -> Result<&'a EnumVariant, EgoError> {
+    definition.variant(name).ok_or_else(|| {
+        EgoError::new(
+            ErrorType::TypeError,
+            format!("Enum '{}' has no variant named '{name}'", definition.name).as_str(),
+            Some(span.clone()),
         )
+    })
+}
+
+// 1 value | 2 values
+fn plural(count: usize, noun: &str) -> String {
+    match count {
+        1 => format!("1 {noun}"),
+        _ => format!("{count} {noun}s"),
     }
-    scopes.pop();
-    return_expr
 }
 
 fn exec_while(
     node: &WhileStatement,
     scopes: &mut ScopesStack,
     _invoker: ScopeInvoker,
-) -> Option<RuntimeType> {
-    let mut return_expr = None;
-    while calc_expression(&node.condition, scopes)
-        .unwrap_or_else(|| {
-            error::throw(
-                ErrorType::InterpretingError,
-                "This is a known possible issue. Please report on https://github.com/noreplydev/ego with your code",
-                Some(node.line),
-            );
-            std::process::exit(1);
-        })
-        .to_boolean() 
-    {
+) -> Result<Option<ControlFlow>, EgoError> {
+    while calc_expression(&node.condition, scopes)?.to_boolean() {
         scopes.push(ScopeInvoker::WhileStatement);
-        return_expr = exec_node(
+        let flow = exec_node(
             &AstNodeType::Block(node.body.clone()),
             scopes,
             ScopeInvoker::WhileStatement,
         );
-        if return_expr.is_some() {
-            scopes.pop();
-            break
+        scopes.pop()?;
+        match flow? {
+            Some(ControlFlow::Break(target, _)) if leaves(&target, &node.label) => break,
+            Some(ControlFlow::Continue(target, _)) if leaves(&target, &node.label) => {}
+            None => {}
+            // return or a jump to an outer loop
+            flow => return Ok(flow),
+        }
+    }
+    Ok(None)
+}
+
+fn exec_for(
+    node: &ForStatement,
+    scopes: &mut ScopesStack,
+    _invoker: ScopeInvoker,
+) -> Result<Option<ControlFlow>, EgoError> {
+    // ranges are not turned into a vector
+    let items: Box<dyn Iterator<Item = RuntimeType>> = match &node.iterable {
+        Expression::RangeExpression(range) => {
+            let (start, end) = range_bounds(range, scopes)?;
+            Box::new((start..end).map(|n| RuntimeType::number(n as f64)))
+        }
+        iterable => match calc_expression(iterable, scopes)? {
+            RuntimeType::RnVector(vector) => Box::new(vector.elements.into_iter()),
+            RuntimeType::RnString(string) => {
+                let chars: Vec<RuntimeType> = string
+                    .to_string()
+                    .chars()
+                    .map(|c| RuntimeType::string(c.to_string()))
+                    .collect();
+                Box::new(chars.into_iter())
+            }
+            value => {
+                return Err(EgoError::new(
+                    ErrorType::TypeError,
+                    format!(
+                        "Cannot iterate over a value of type '{}'",
+                        value.type_name()
+                    )
+                    .as_str(),
+                    Some(iterable.span().clone()),
+                ));
+            }
+        },
+    };
+
+    for item in items {
+        // each iteration gets its own item binding
+        scopes.push(ScopeInvoker::ForStatement);
+        let flow = scopes
+            .add_identifier(node.item.name.clone(), item)
+            .map_err(|e| e.with_span(node.item.span.clone()))
+            .and_then(|_| {
+                exec_node(
+                    &AstNodeType::Block(node.body.clone()),
+                    scopes,
+                    ScopeInvoker::ForStatement,
+                )
+            });
+        scopes.pop()?;
+        match flow? {
+            Some(ControlFlow::Break(target, _)) if leaves(&target, &node.label) => break,
+            Some(ControlFlow::Continue(target, _)) if leaves(&target, &node.label) => {}
+            None => {}
+            // return or a jump to an outer loop
+            flow => return Ok(flow),
+        }
+    }
+    Ok(None)
+}
+
+// unlabeled jumps leave the innermost loop
+fn leaves(target: &Option<String>, label: &Option<Identifier>) -> bool {
+    match target {
+        Some(target) => label.as_ref().is_some_and(|label| &label.name == target),
+        None => true,
+    }
+}
+
+// start..end bounds, end is not included
+fn range_bounds(range: &RangeExpression, scopes: &mut ScopesStack) -> Result<(i64, i64), EgoError> {
+    let mut bounds = vec![];
+    for bound in [&range.start, &range.end] {
+        match calc_expression(bound, scopes)? {
+            RuntimeType::RnNumber(n) if n.val.fract() == 0.0 => bounds.push(n.val as i64),
+            value => {
+                return Err(EgoError::new(
+                    ErrorType::TypeError,
+                    format!(
+                        "Range bounds must be whole numbers but got '{}'",
+                        value.to_string()
+                    )
+                    .as_str(),
+                    Some(bound.span().clone()),
+                ));
+            }
+        }
+    }
+    Ok((bounds[0], bounds[1]))
+}
+
+// value of a property or method read with the dot operator
+fn member_value(object: RuntimeType, node: &MemberExpression) -> Result<RuntimeType, EgoError> {
+    let name = &node.property.name;
+    match object {
+        // undefined properties are just nothing
+        RuntimeType::RnObject(object) => {
+            Ok(object.get(name).cloned().unwrap_or(RuntimeType::nothing()))
+        }
+        // fields first, then the methods of its struct
+        RuntimeType::RnInstance(instance) => match instance.fields.get(name) {
+            Some(value) => Ok(value.clone()),
+            None => match instance.definition.method(name) {
+                Some(method) => Ok(RuntimeType::RnFunction(method.clone())),
+                None => Err(unknown_field(instance.name(), name, node.span.clone())),
+            },
+        },
+        // variants without payload are values, the rest are created by calling them
+        RuntimeType::RnEnum(rn_enum) => {
+            let variant = enum_variant(&rn_enum, name, &node.span)?;
+            if !variant.fields.is_empty() {
+                return Err(EgoError::new(
+                    ErrorType::TypeError,
+                    format!(
+                        "Variant '{}.{name}' expects {}, create it with '{}.{name}(...)'",
+                        rn_enum.name,
+                        plural(variant.fields.len(), "value"),
+                        rn_enum.name
+                    )
+                    .as_str(),
+                    Some(node.span.clone()),
+                ));
+            }
+            Ok(RuntimeType::variant(Rc::new(rn_enum), name.clone(), vec![]))
+        }
+        RuntimeType::RnVariant(rn_variant) => match rn_variant.fields.get(name) {
+            Some(value) => Ok(value.clone()),
+            None => Err(EgoError::new(
+                ErrorType::TypeError,
+                format!(
+                    "Variant '{}.{}' has no field named '{name}'",
+                    rn_variant.enum_name(),
+                    rn_variant.name
+                )
+                .as_str(),
+                Some(node.span.clone()),
+            )),
+        },
+        RuntimeType::RnStruct(rn_struct) => match rn_struct.method(name) {
+            Some(method) => Ok(RuntimeType::RnFunction(method.clone())),
+            None => Err(EgoError::new(
+                ErrorType::TypeError,
+                format!("Struct '{}' has no method named '{name}'", rn_struct.name).as_str(),
+                Some(node.span.clone()),
+            )),
+        },
+        _ => Err(EgoError::new(
+            ErrorType::TypeError,
+            format!(
+                "Cannot read property '{name}' of a value of type '{}'",
+                object.type_name()
+            )
+            .as_str(),
+            Some(node.span.clone()),
+        )),
+    }
+}
+
+fn takes_self(function: &RuntimeType) -> bool {
+    match function {
+        RuntimeType::RnFunction(function) => function
+            .parameters
+            .first()
+            .is_some_and(|parameter| parameter.name == "self"),
+        _ => false,
+    }
+}
+
+// Point { x: 1, y: 2 }
+fn calc_struct(node: &StructExpression, scopes: &mut ScopesStack) -> Result<RuntimeType, EgoError> {
+    let name = &node.identifier.name;
+    let definition = match scopes
+        .get_identifier_value(name)
+        .map_err(|e| e.with_span(node.identifier.span.clone()))?
+    {
+        RuntimeType::RnStruct(definition) => Rc::new(definition),
+        value => {
+            return Err(EgoError::new(
+                ErrorType::TypeError,
+                format!(
+                    "Cannot create an instance of '{name}' because it is a value of type '{}' and not a struct",
+                    value.type_name()
+                )
+                .as_str(),
+                Some(node.identifier.span.clone()),
+            ))
         }
-        scopes.pop();
+    };
+
+    let mut values = vec![];
+    for (key, value) in &node.fields.properties {
+        if !definition.fields.iter().any(|field| field.name == key.name) {
+            return Err(unknown_field(name, &key.name, key.span.clone()));
+        }
+        values.push((key.name.clone(), calc_expression(value, scopes)?));
+    }
+
+    // fields keep the declaration order, missing ones are nothing
+    let mut fields = vec![];
+    for field in &definition.fields {
+        let value = values
+            .iter()
+            .find(|(key, _)| *key == field.name)
+            .map(|(_, value)| value.clone())
+            .unwrap_or(RuntimeType::nothing());
+        check_field(name, field, &value, &node.span)?;
+        fields.push((field.name.clone(), value));
+    }
+    Ok(RuntimeType::instance(definition, fields))
+}
+
+fn check_field(
+    struct_name: &str,
+    field: &Identifier,
+    value: &RuntimeType,
+    span: &Span,
+) -> Result<(), EgoError> {
+    match &field.annotation {
+        Some(annotation) if !has_type(value, annotation) => Err(EgoError::new(
+            ErrorType::TypeError,
+            format!(
+                "Field '{}' of '{struct_name}' expects a value of type '{annotation}' but got '{}'",
+                field.name,
+                value.type_name()
+            )
+            .as_str(),
+            Some(span.clone()),
+        )),
+        _ => Ok(()),
     }
-    return_expr
+}
+
+fn unknown_field(struct_name: &str, name: &str, span: Span) -> EgoError {
+    EgoError::new(
+        ErrorType::TypeError,
+        format!("Struct '{struct_name}' has no field or method named '{name}'").as_str(),
+        Some(span),
+    )
+}
+
+// whether a value matches a type annotation
+fn has_type(value: &RuntimeType, annotation: &Type) -> bool {
+    matches!(
+        (annotation, value),
+        (Type::Bool, RuntimeType::RnBoolean(_))
+            | (Type::Number, RuntimeType::RnNumber(_))
+            | (Type::String, RuntimeType::RnString(_))
+            | (Type::Nothing, RuntimeType::Nothing(_))
+    )
+}
 
+fn outside_loop_error(span: Span) -> EgoError {
+    EgoError::new(
+        ErrorType::SyntaxError,
+        "'break' and 'continue' are only valid inside a loop",
+        Some(span),
+    )
 }
 
-fn calc_expression(node: &Expression, scopes: &mut ScopesStack) -> Option<RuntimeType> {
+fn calc_expression(node: &Expression, scopes: &mut ScopesStack) -> Result<RuntimeType, EgoError> {
     match node {
-        Expression::Bool(v) => Some(RuntimeType::boolean(v.value)),
-        Expression::Number(v) => Some(RuntimeType::number(v.value)),
-        Expression::StringLiteral(v) => Some(RuntimeType::string(v.value.clone(), false)),
-        Expression::Nothing(_) => Some(RuntimeType::nothing()),
-        Expression::Identifier(v) => {
-            if let Some(val) = scopes.get_identifier_value(&v.name) {
-                Some(val.clone()) // now we are cloning the value, so
-                                  // it's not like passing the reference
-            } else {
-                None
+        Expression::Bool(v) => Ok(RuntimeType::boolean(v.value)),
+        Expression::Number(v) => Ok(RuntimeType::number(v.value)),
+        Expression::StringLiteral(v) => Ok(RuntimeType::string(v.value.clone())),
+        Expression::TemplateString(v) => {
+            let mut string = String::new();
+            for part in &v.parts {
+                string.push_str(&calc_expression(part, scopes)?.to_string());
+            }
+            Ok(RuntimeType::string(string))
+        }
+        Expression::Nothing(_) => Ok(RuntimeType::nothing()),
+        Expression::Vector(v) => {
+            let mut elements = vec![];
+            for element in &v.children {
+                if let Some(element) = element {
+                    elements.push(calc_expression(element, scopes)?);
+                } else {
+                    elements.push(RuntimeType::nothing());
+                }
+            }
+            Ok(RuntimeType::vector(elements))
+        }
+        Expression::IndexExpression(v) => {
+            let object = calc_expression(&v.object, scopes)?;
+            let index = calc_expression(&v.index, scopes)?;
+            match object {
+                RuntimeType::RnVector(vector) => {
+                    let position = vector_index(&index, vector.len(), v.span.clone())?;
+                    Ok(vector
+                        .get(position)
+                        .cloned()
+                        .unwrap_or(RuntimeType::nothing()))
+                }
+                RuntimeType::RnString(string) => {
+                    let chars: Vec<char> = string.to_string().chars().collect();
+                    let position = vector_index(&index, chars.len(), v.span.clone())?;
+                    Ok(RuntimeType::string(chars[position].to_string()))
+                }
+                _ => Err(EgoError::new(
+                    ErrorType::TypeError,
+                    format!("Cannot index a value of type '{}'", object.type_name()).as_str(),
+                    Some(v.span.clone()),
+                )),
             }
         }
+        Expression::Object(v) => {
+            let mut properties = vec![];
+            for (key, value) in &v.properties {
+                let value = calc_expression(value, scopes)?;
+                properties.push((key.name.clone(), value));
+            }
+            Ok(RuntimeType::object(properties))
+        }
+        Expression::MemberExpression(v) => {
+            let object = calc_expression(&v.object, scopes)?;
+            member_value(object, v)
+        }
+        Expression::StructExpression(v) => calc_struct(v, scopes),
+        Expression::Identifier(v) => {
+            // now we are cloning the value, so
+            // it's not like passing the reference
+            scopes
+                .get_identifier_value(&v.name)
+                .map_err(|e| e.with_span(v.span.clone()))
+        }
+        Expression::RangeExpression(v) => {
+            let (start, end) = range_bounds(v, scopes)?;
+            let elements = (start..end).map(|n| RuntimeType::number(n as f64));
+            Ok(RuntimeType::vector(elements.collect()))
+        }
+        Expression::IfExpression(v) => calc_if(v, scopes),
+        Expression::MatchExpression(v) => calc_match(v, scopes),
+        Expression::FunctionExpression(v) => Ok(RuntimeType::function(
+            ANONYMOUS_FUNCTION.to_string(),
+            v.parameters.clone(),
+            v.return_type,
+            v.body.clone(),
+            v.span.clone(),
+            scopes.capture(),
+        )),
+        Expression::BinaryExpression(expr) if expr.operator == "&&" || expr.operator == "||" => {
+            // the right operand only runs when it decides the result
+            let left = calc_expression(&expr.left, scopes)?.to_boolean();
+            let value = match expr.operator.as_str() {
+                "&&" => left && calc_expression(&expr.right, scopes)?.to_boolean(),
+                _ => left || calc_expression(&expr.right, scopes)?.to_boolean(),
+            };
+            Ok(RuntimeType::boolean(value))
+        }
         Expression::BinaryExpression(expr) => {
-            let left = calc_expression(&expr.left, scopes);
-            let right = calc_expression(&expr.right, scopes);
-            match left {
-                Some(_left) => match right {
-                    Some(_right) => {
-                        let result = _left.arithmetic(expr.operator.as_str(), _right);
-                        match result {
-                            Ok(val) => Some(val),
-                            Err(err) => {
-                                error::throw(
-                                    err,
-                                    expr.operator.to_string().as_str(),
-                                    Some(expr.line),
-                                );
-                                std::process::exit(1);
-                            }
-                        }
-                    }
-                    None => None,
-                },
-                None => None,
+            let left = calc_expression(&expr.left, scopes)?;
+            let right = calc_expression(&expr.right, scopes)?;
+
+            // '/' follows floats and gives inf, but an integer
+            // division or remainder by zero has no sensible result
+            if let (RuntimeType::RnNumber(_), RuntimeType::RnNumber(divisor), "~/" | "%") =
+                (&left, &right, expr.operator.as_str())
+            {
+                if divisor.val == 0.0 {
+                    return Err(EgoError::new(
+                        ErrorType::ArithmeticError,
+                        format!("Cannot apply '{}' with a divisor of zero", expr.operator).as_str(),
+                        Some(expr.span.clone()),
+                    ));
+                }
+            }
+
+            left.arithmetic(expr.operator.as_str(), right)
+                .map_err(|err| EgoError::new(err, expr.operator.as_str(), Some(expr.span.clone())))
+        }
+        Expression::UnaryExpression(expr) => {
+            let operand = calc_expression(&expr.operand, scopes)?;
+            match (expr.operator.as_str(), operand) {
+                ("!", operand) => Ok(RuntimeType::boolean(!operand.to_boolean())),
+                ("-", RuntimeType::RnNumber(n)) => Ok(RuntimeType::number(-n.val)),
+                (operator, operand) => Err(EgoError::new(
+                    ErrorType::TypeError,
+                    format!(
+                        "Cannot apply '{}' to a value of type '{}'",
+                        operator,
+                        operand.type_name()
+                    )
+                    .as_str(),
+                    Some(expr.span.clone()),
+                )),
             }
         }
         Expression::CallExpression(node) => {
-            let runtime_arguments: Vec<RuntimeType> = node
-                .arguments
-                .children
-                .iter()
-                .map(|arg| -> RuntimeType {
-                    if let Some(arg) = arg {
-                        calc_expression(&arg, scopes).unwrap_or_else(|| {
-                            error::throw(
-                                ErrorType::InterpretingError,
-                                "This is a known possible issue. Please report on https://github.com/noreplydev/ego with your code",
-                                Some(node.line),
-                            );
-                            std::process::exit(1);
-                        })
-                    } else {
-                        RuntimeType::nothing()
+            let mut runtime_arguments: Vec<RuntimeType> = vec![];
+            for arg in &node.arguments.children {
+                if let Some(arg) = arg {
+                    runtime_arguments.push(calc_expression(arg, scopes)?);
+                } else {
+                    runtime_arguments.push(RuntimeType::nothing());
+                }
+            }
+
+            let function = match node.callee.as_ref() {
+                // p.len() passes p as the self parameter of the method
+                Expression::MemberExpression(member) => {
+                    let object = calc_expression(&member.object, scopes)?;
+                    if let RuntimeType::RnEnum(definition) = object {
+                        return calc_variant(definition, member, runtime_arguments, &node.span);
+                    }
+                    let function = member_value(object.clone(), member)?;
+                    if let RuntimeType::RnInstance(instance) = &object {
+                        let is_method = instance.fields.get(&member.property.name).is_none();
+                        if is_method && takes_self(&function) {
+                            runtime_arguments.insert(0, object);
+                        }
                     }
-                })
-                .collect();
+                    function
+                }
+                Expression::Identifier(identifier) => {
+                    scopes.get_identifier_value(&identifier.name).map_err(|_| {
+                        EgoError::new(
+                            ErrorType::ReferenceError,
+                            format!("Function '{}' has not been defined", identifier.name).as_str(),
+                            Some(node.span.clone()),
+                        )
+                    })?
+                }
+                callee => calc_expression(callee, scopes)?,
+            };
+
+            let identifier = match node.callee_name() {
+                Some(name) => name.to_string(),
+                None => function.to_string(),
+            };
+            call_function(
+                &identifier,
+                function,
+                runtime_arguments,
+                scopes,
+                Some(node.span.clone()),
+            )
+        }
+    }
+}
+
+fn call_function(
+    identifier: &str,
+    function: RuntimeType,
+    arguments: Vec<RuntimeType>,
+    scopes: &mut ScopesStack,
+    span: Option<Span>,
+) -> Result<RuntimeType, EgoError> {
+    match function {
+        RuntimeType::RnFunction(func) => {
+            if scopes.call_depth() >= MAX_CALL_DEPTH {
+                return Err(EgoError::new(
+                    ErrorType::RangeError,
+                    format!(
+                        "Maximum call depth of {MAX_CALL_DEPTH} exceeded calling '{identifier}'"
+                    )
+                    .as_str(),
+                    span,
+                )
+                .with_trace(scopes.trace()));
+            }
+
+            // the body runs over the scopes where the
+            // function was defined, not the caller ones
+            let caller = scopes.enter(&func.environment);
 
             // push new hashmap onto the stack
             // for function scope
             scopes.push(ScopeInvoker::Function);
-            let call_expression_return = match node.identifier.name.as_str() {
-                "print" => print(runtime_arguments, scopes),
-                "type" => {
-                    if runtime_arguments.len() > 0 {
-                        type_of(runtime_arguments[0].clone())
-                    } else {
-                        error::throw(
-                            ErrorType::SyntaxError,
-                            "type(...) requires one parameter of <any> type in it's call",
-                            Some(node.line),
-                        );
-                        std::process::exit(1);
+            scopes.push_frame(Frame::new(identifier, span.clone()));
+            let mut arguments = arguments.into_iter();
+            let mut call_return = Ok(None);
+            for parameter in func.parameters.iter() {
+                let value = arguments.next().unwrap_or(RuntimeType::nothing());
+                if let Some(annotation) = &parameter.annotation {
+                    if !has_type(&value, annotation) {
+                        call_return = Err(EgoError::new(
+                            ErrorType::TypeError,
+                            format!(
+                                "Parameter '{}' of '{identifier}' expects a value of type '{annotation}' but got '{}'",
+                                parameter.name,
+                                value.type_name()
+                            )
+                            .as_str(),
+                            Some(span.clone().unwrap_or(parameter.span.clone())),
+                        ));
+                        break;
                     }
                 }
-                _ => {
-                    let function = scopes
-                        .get_identifier_value(&node.identifier.name)
-                        .unwrap_or_else(|| {
-                            error::throw(
-                                ErrorType::ReferenceError,
-                                format!("Function '{}' has not been defined", node.identifier.name)
-                                    .as_str(),
-                                Some(node.line),
-                            );
-                            std::process::exit(1);
-                        });
-
-                    let (parameters, body) = match function {
-                        RuntimeType::RnFunction(func) => {
-                            (func.parameters.clone(), func.body.clone())
-                        }
-                        _ => {
-                            error::throw(
-                                ErrorType::ReferenceError,
-                                format!("Identifier '{}' is not callable", node.identifier.name)
-                                    .as_str(),
-                                Some(node.line),
-                            );
-                            std::process::exit(1);
-                        }
-                    };
-
-                    for (i, parameter) in parameters.iter().enumerate() {
-                        if i < runtime_arguments.len() {
-                            scopes.add_identifier(
-                                parameter.name.clone(),
-                                runtime_arguments[i].clone(),
-                            );
-                        } else {
-                            scopes.add_identifier(parameter.name.clone(), RuntimeType::nothing());
-                        }
-                    }
+                if let Err(e) = scopes.add_identifier(parameter.name.clone(), value) {
+                    call_return = Err(match span.clone() {
+                        Some(span) => e.with_span(span),
+                        None => e,
+                    });
+                    break;
+                }
+            }
 
-                    exec_node(&AstNodeType::Block(body), scopes, ScopeInvoker::Function)
+            if call_return.is_ok() {
+                call_return = exec_node(
+                    &AstNodeType::Block(func.body),
+                    scopes,
+                    ScopeInvoker::Function,
+                );
+            }
+            let call_return = call_return.map_err(|e| e.with_trace(scopes.trace()));
+            scopes.pop_frame();
+            let popped = scopes.pop();
+            scopes.leave(caller);
+            popped?;
+            let value = match call_return? {
+                Some(ControlFlow::Return(value)) => value,
+                Some(ControlFlow::Break(_, span)) | Some(ControlFlow::Continue(_, span)) => {
+                    return Err(outside_loop_error(span));
                 }
+                None => RuntimeType::nothing(),
             };
-            scopes.pop();
-            call_expression_return
+            if let Some(annotation) = &func.return_type {
+                if !has_type(&value, annotation) {
+                    return Err(EgoError::new(
+                        ErrorType::TypeError,
+                        format!(
+                            "Function '{identifier}' must return a value of type '{annotation}' but returned '{}'",
+                            value.type_name()
+                        )
+                        .as_str(),
+                        Some(span.unwrap_or(func.span)),
+                    ));
+                }
+            }
+            Ok(value)
         }
+        RuntimeType::RnNativeFunction(func) => func
+            .call(&arguments)
+            .map_err(|(error_type, message)| EgoError::new(error_type, message.as_str(), span)),
+        _ => Err(EgoError::new(
+            ErrorType::ReferenceError,
+            format!("Identifier '{identifier}' is not callable").as_str(),
+            span,
+        )),
     }
 }
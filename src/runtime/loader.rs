@@ -0,0 +1,139 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use crate::{
+    ast::{import_statement::ImportStatement, lex, Module},
+    core::{
+        error::{EgoError, ErrorType},
+        runtypes::RuntimeType,
+        stdlib::{random_state, std_module, RandomState},
+    },
+};
+
+use super::{Interpreter, NativeRegistry};
+
+// shared between the interpreters of every module
+// loaded in the same run
+#[derive(Debug)]
+pub struct ModuleLoader {
+    cache: HashMap<PathBuf, HashMap<String, RuntimeType>>,
+    loading: Vec<PathBuf>,
+    random: RandomState,
+}
+
+impl ModuleLoader {
+    // the entry module is marked as loading so importing
+    // it back is reported as a cycle
+    pub fn new(entry_module: &str) -> Rc<RefCell<ModuleLoader>> {
+        let mut loader = ModuleLoader {
+            cache: HashMap::new(),
+            loading: vec![],
+            random: random_state(),
+        };
+        if let Ok(entry_path) = fs::canonicalize(entry_module) {
+            loader.loading.push(entry_path);
+        }
+        Rc::new(RefCell::new(loader))
+    }
+}
+
+// import utils/math.[add] -> <importer dir>/utils/math.ego
+pub fn resolve_module_path(importer: &str, node: &ImportStatement) -> PathBuf {
+    let base = Path::new(importer).parent().unwrap_or(Path::new(""));
+    let mut path = base.join(node.module.join("/"));
+    path.set_extension("ego");
+    path
+}
+
+// loads, parses and executes the module only the first time,
+// returning its module level identifiers
+pub fn load_module(
+    loader: &Rc<RefCell<ModuleLoader>>,
+    natives: &NativeRegistry,
+    importer: &str,
+    node: &ImportStatement,
+) -> Result<HashMap<String, RuntimeType>, EgoError> {
+    let module_name = node.module.join("/");
+
+    // import std/io.[read_input]
+    if node.module.len() > 1 && node.module[0] == "std" {
+        let random = loader.borrow().random.clone();
+        return std_module(&node.module[1..].join("/"), &random).ok_or_else(|| {
+            EgoError::new(
+                ErrorType::ImportError,
+                format!("Unknown standard library module '{module_name}'").as_str(),
+                Some(node.span.clone()),
+            )
+        });
+    }
+
+    let module_path = resolve_module_path(importer, node);
+
+    let canonical_path = fs::canonicalize(&module_path).map_err(|_| {
+        EgoError::new(
+            ErrorType::ImportError,
+            format!(
+                "Cannot find module '{module_name}' (looked at '{}')",
+                module_path.display()
+            )
+            .as_str(),
+            Some(node.span.clone()),
+        )
+    })?;
+
+    if let Some(exports) = loader.borrow().cache.get(&canonical_path) {
+        return Ok(exports.clone());
+    }
+
+    if loader.borrow().loading.contains(&canonical_path) {
+        let mut cycle: Vec<String> = loader
+            .borrow()
+            .loading
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        cycle.push(canonical_path.display().to_string());
+        return Err(EgoError::new(
+            ErrorType::ImportError,
+            format!("Import cycle detected: {}", cycle.join(" -> ")).as_str(),
+            Some(node.span.clone()),
+        ));
+    }
+
+    let source = fs::read_to_string(&canonical_path).map_err(|_| {
+        EgoError::new(
+            ErrorType::ImportError,
+            format!("Cannot read module '{module_name}'").as_str(),
+            Some(node.span.clone()),
+        )
+    })?;
+
+    loader.borrow_mut().loading.push(canonical_path.clone());
+
+    let result = exec_module(loader, natives, &module_path, source);
+
+    let mut loader = loader.borrow_mut();
+    loader.loading.pop();
+    let exports = result?;
+    loader.cache.insert(canonical_path, exports.clone());
+    Ok(exports)
+}
+
+fn exec_module(
+    loader: &Rc<RefCell<ModuleLoader>>,
+    natives: &NativeRegistry,
+    module_path: &Path,
+    source: String,
+) -> Result<HashMap<String, RuntimeType>, EgoError> {
+    let tokens = lex(source, &module_path.display().to_string())?;
+    let mut module = Module::new(module_path.display().to_string(), tokens);
+    let ast = module.parse()?;
+    let mut interpreter = Interpreter::with_context(ast, loader.clone(), natives.clone());
+    interpreter.exec(false)?;
+    Ok(interpreter.module_identifiers())
+}
@@ -1,5 +1,12 @@
 mod interpreter;
+mod loader;
+mod natives;
 mod scope;
 
 pub use self::interpreter::Interpreter;
-pub use self::scope::ScopesStack;
+pub use self::natives::NativeRegistry;
+pub use self::scope::{Environment, ScopesStack};
+
+// only exposed by the library
+#[allow(unused_imports)]
+pub use self::scope::live_scopes;
@@ -0,0 +1,49 @@
+use crate::core::{
+    handlers::{print_handler::print, type_handler::type_of},
+    runtypes::{NativeResult, RnNativeFunction, RuntimeType},
+};
+
+// host provided functions, defined in the prelude scope
+// so ego code can shadow them like any other identifier
+#[derive(Debug, Clone)]
+pub struct NativeRegistry {
+    functions: Vec<RnNativeFunction>,
+}
+
+impl NativeRegistry {
+    pub fn new() -> NativeRegistry {
+        NativeRegistry { functions: vec![] }
+    }
+
+    // print(...) and type(...)
+    pub fn with_builtins() -> NativeRegistry {
+        let mut registry = NativeRegistry::new();
+        registry.register("print", None, print);
+        registry.register("type", Some(1), type_of);
+        registry
+    }
+
+    // registering an existing name replaces the previous function
+    pub fn register<F>(&mut self, name: &str, arity: Option<usize>, handler: F)
+    where
+        F: Fn(&[RuntimeType]) -> NativeResult + 'static,
+    {
+        let function = RnNativeFunction::new(name, arity, handler);
+        match self.functions.iter_mut().find(|f| f.name == name) {
+            Some(registered) => *registered = function,
+            None => self.functions.push(function),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&RnNativeFunction> {
+        self.functions.iter().find(|f| f.name == name)
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.functions.iter().map(|f| f.name.clone()).collect()
+    }
+
+    pub fn functions(&self) -> &Vec<RnNativeFunction> {
+        &self.functions
+    }
+}
@@ -1,128 +1,394 @@
-use std::collections::HashMap;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    fmt,
+    rc::{Rc, Weak},
+};
 
 use crate::core::{
-    error::{self, ErrorType},
-    runtypes::RuntimeType,
+    error::{EgoError, ErrorType, Frame},
+    runtypes::{RnFunction, RnObject, RuntimeType},
 };
 
-#[derive(Debug, Clone)]
+thread_local! {
+    static LIVE_SCOPES: Cell<usize> = const { Cell::new(0) };
+}
+
+// scopes alive in the current thread, finished calls
+// must not leave theirs behind
+#[allow(dead_code)]
+pub fn live_scopes() -> usize {
+    LIVE_SCOPES.with(|count| count.get())
+}
+
+// scopes are shared so functions can capture the ones they
+// were defined in and still see later assignments to them
+#[derive(Debug)]
 pub struct ScopesStack {
-    scopes: Vec<Scope>,
+    scopes: Vec<Rc<RefCell<Scope>>>,
+    frames: Vec<Frame>,
 }
 
 impl ScopesStack {
     pub fn new(invoker: ScopeInvoker) -> ScopesStack {
         ScopesStack {
-            scopes: vec![Scope::new(invoker)],
+            scopes: vec![Rc::new(RefCell::new(Scope::new(invoker)))],
+            frames: vec![],
         }
     }
 
-    pub fn add_identifier(&mut self, identifier: String, value: RuntimeType) {
-        if let Some(scope) = self.scopes.last_mut() {
-            scope.add(identifier, value);
+    // scopes visible from the current point of execution
+    pub fn capture(&self) -> Environment {
+        Environment {
+            scopes: self.scopes.iter().cloned().map(Captured::Strong).collect(),
         }
     }
 
-    // this function must to be recursive since the target variable can be defined in another scope
-    pub fn set_indentifier(&mut self, identifier: String, value: RuntimeType) {
-        let mut counter = self.scopes.len() - 1;
+    // runs on top of a captured environment until leave
+    // is called with the returned caller environment
+    pub fn enter(&mut self, environment: &Environment) -> Environment {
+        let caller = std::mem::replace(&mut self.scopes, environment.upgrade());
+        Environment {
+            scopes: caller.into_iter().map(Captured::Strong).collect(),
+        }
+    }
+
+    pub fn leave(&mut self, caller: Environment) {
+        self.scopes = caller.upgrade();
+    }
+
+    // function calls being executed
+    pub fn push_frame(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    pub fn pop_frame(&mut self) {
+        self.frames.pop();
+    }
+
+    pub fn call_depth(&self) -> usize {
+        self.frames.len()
+    }
+
+    // current calls, innermost first
+    pub fn trace(&self) -> Vec<Frame> {
+        self.frames.iter().rev().cloned().collect()
+    }
 
-        while counter >= 0 {
-            if let Some(_) = self.scopes[counter].get(&identifier) {
-                self.scopes[counter].set(identifier.clone(), value);
-                break;
-            } else if counter == 0 {
-                error::throw(
-                    ErrorType::ReferenceError,
-                    format!("identifier '{identifier}' was not declared").as_str(),
-                    None,
-                );
+    pub fn add_identifier(
+        &mut self,
+        identifier: String,
+        value: RuntimeType,
+    ) -> Result<(), EgoError> {
+        match self.scopes.last() {
+            Some(scope) => {
+                let value = stored_in(value, scope);
+                scope.borrow_mut().add(identifier, value)
             }
-            counter -= 1;
+            None => Ok(()),
         }
     }
 
-    pub fn get_identifier_value(&self, identifier: &String) -> Option<&RuntimeType> {
-        let mut counter = self.scopes.len() - 1;
+    // same as add_identifier but it can't be reassigned later
+    pub fn add_constant(&mut self, identifier: String, value: RuntimeType) -> Result<(), EgoError> {
+        match self.scopes.last() {
+            Some(scope) => {
+                let value = stored_in(value, scope);
+                let mut scope = scope.borrow_mut();
+                scope.add(identifier.clone(), value)?;
+                scope.constants.insert(identifier);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    // defines or replaces an identifier in the innermost scope
+    pub fn set_local_identifier(&mut self, identifier: String, value: RuntimeType) {
+        if let Some(scope) = self.scopes.last() {
+            let value = stored_in(value, scope);
+            scope.borrow_mut().set(identifier, value);
+        }
+    }
 
-        while counter >= 0 {
-            if let Some(identifier_value) = self.scopes[counter].get(identifier) {
-                return Some(identifier_value);
-            } else if counter == 0 {
-                error::throw(
-                    ErrorType::ReferenceError,
-                    format!("identifier '{identifier}' was not declared").as_str(),
-                    None,
-                );
+    // the target variable can be defined in any scope of the stack
+    pub fn set_indentifier(
+        &mut self,
+        identifier: String,
+        value: RuntimeType,
+    ) -> Result<(), EgoError> {
+        for scope_ref in self.scopes.iter().rev() {
+            let mut scope = scope_ref.borrow_mut();
+            if scope.get(&identifier).is_some() {
+                if scope.constants.contains(&identifier) {
+                    return Err(EgoError::new(
+                        ErrorType::ConstantReassignmentError,
+                        format!("Cannot assign to '{identifier}' because it is a constant")
+                            .as_str(),
+                        None,
+                    ));
+                }
+                scope.set(identifier, stored_in(value, scope_ref));
+                return Ok(());
             }
-            counter -= 1;
         }
 
-        error::throw(
+        Err(EgoError::new(
             ErrorType::ReferenceError,
             format!("identifier '{identifier}' was not declared").as_str(),
             None,
-        );
-        std::process::exit(1);
+        ))
+    }
+
+    pub fn get_identifier_value(&self, identifier: &String) -> Result<RuntimeType, EgoError> {
+        self.find_identifier(identifier).ok_or_else(|| {
+            EgoError::new(
+                ErrorType::ReferenceError,
+                format!("identifier '{identifier}' was not declared").as_str(),
+                None,
+            )
+        })
+    }
+
+    // same lookup as get_identifier_value but missing identifiers are not an error
+    pub fn find_identifier(&self, identifier: &str) -> Option<RuntimeType> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.borrow().vars.get(identifier).cloned())
+            .map(read_from_scope)
+    }
+
+    // defines or replaces an identifier in the outermost scope
+    pub fn set_prelude_identifier(&mut self, identifier: String, value: RuntimeType) {
+        if let Some(scope) = self.scopes.first() {
+            let value = stored_in(value, scope);
+            scope.borrow_mut().set(identifier, value);
+        }
+    }
+
+    // defines or replaces an identifier in the module scope
+    pub fn set_module_identifier(&mut self, identifier: String, value: RuntimeType) {
+        if let Some(scope) = self
+            .scopes
+            .iter()
+            .find(|scope| matches!(scope.borrow().invoker, ScopeInvoker::Module))
+        {
+            let value = stored_in(value, scope);
+            scope.borrow_mut().set(identifier, value);
+        }
+    }
+
+    // identifiers defined at the module level
+    pub fn module_identifiers(&self) -> HashMap<String, RuntimeType> {
+        let module_scope = self
+            .scopes
+            .iter()
+            .find(|scope| matches!(scope.borrow().invoker, ScopeInvoker::Module));
+
+        match module_scope {
+            Some(scope) => scope
+                .borrow()
+                .vars
+                .iter()
+                .map(|(name, value)| (name.clone(), read_from_scope(value.clone())))
+                .collect(),
+            None => HashMap::new(),
+        }
     }
 
     pub fn push(&mut self, invoker: ScopeInvoker) {
-        self.scopes.push(Scope::new(invoker));
+        self.scopes.push(Rc::new(RefCell::new(Scope::new(invoker))));
     }
 
-    pub fn pop(&mut self) {
-        let pop_status = self.scopes.pop();
-        if pop_status.is_none() {
-            error::throw(
+    pub fn depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    // drops every scope above the given depth
+    pub fn truncate(&mut self, depth: usize) {
+        self.scopes.truncate(depth);
+    }
+
+    pub fn pop(&mut self) -> Result<(), EgoError> {
+        match self.scopes.pop() {
+            Some(_) => Ok(()),
+            None => Err(EgoError::new(
                 ErrorType::StackUnderflowError,
-                "Error: Stack Underflow Detected\nThe program attempted to exit a scope when none are active. This usually indicates a mismatch in the creation and destruction of scopes, such as exiting more blocks or functions than were entered. Please review your code for any discrepancies in scope management, ensuring that each entered scope or function block is properly exited.", 
-                None
-            );
+                "Error: Stack Underflow Detected\nThe program attempted to exit a scope when none are active. This usually indicates a mismatch in the creation and destruction of scopes, such as exiting more blocks or functions than were entered. Please review your code for any discrepancies in scope management, ensuring that each entered scope or function block is properly exited.",
+                None,
+            )),
         }
     }
 }
 
+// a function stored in the scope it captured holds that
+// scope weakly, otherwise neither of them would be freed
+#[derive(Clone)]
+enum Captured {
+    Strong(Rc<RefCell<Scope>>),
+    Weak(Weak<RefCell<Scope>>),
+}
+
+// scopes captured by a function when it is defined
+#[derive(Clone)]
+pub struct Environment {
+    scopes: Vec<Captured>,
+}
+
+impl Environment {
+    // weak scopes are alive while the function is read from
+    // the scope that stores it
+    fn upgrade(&self) -> Vec<Rc<RefCell<Scope>>> {
+        self.scopes
+            .iter()
+            .filter_map(|scope| match scope {
+                Captured::Strong(scope) => Some(scope.clone()),
+                Captured::Weak(scope) => scope.upgrade(),
+            })
+            .collect()
+    }
+
+    // none when the scope is not captured
+    fn weaken(&self, target: &Rc<RefCell<Scope>>) -> Option<Environment> {
+        let captured = |scope: &Captured| matches!(scope, Captured::Strong(scope) if Rc::ptr_eq(scope, target));
+        if !self.scopes.iter().any(captured) {
+            return None;
+        }
+
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|scope| match scope {
+                Captured::Strong(scope) if Rc::ptr_eq(scope, target) => {
+                    Captured::Weak(Rc::downgrade(scope))
+                }
+                scope => scope.clone(),
+            })
+            .collect();
+        Some(Environment { scopes })
+    }
+
+    // none when every scope is already held strongly
+    fn strengthen(&self) -> Option<Environment> {
+        if !self
+            .scopes
+            .iter()
+            .any(|scope| matches!(scope, Captured::Weak(_)))
+        {
+            return None;
+        }
+
+        let scopes = self.upgrade().into_iter().map(Captured::Strong).collect();
+        Some(Environment { scopes })
+    }
+}
+
+// captured scopes can hold the function itself
+impl fmt::Debug for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Environment({} scopes)", self.scopes.len())
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum ScopeInvoker {
+    Prelude,
     Module,
     IfStatement,
     WhileStatement,
+    ForStatement,
+    MatchArm,
     Function,
 }
-#[derive(Debug, Clone)]
+// values keep the scope they are stored in weakly
+fn stored_in(mut value: RuntimeType, scope: &Rc<RefCell<Scope>>) -> RuntimeType {
+    map_environments(&mut value, &|environment| environment.weaken(scope));
+    value
+}
+
+// and every scope strongly once they leave it
+fn read_from_scope(mut value: RuntimeType) -> RuntimeType {
+    map_environments(&mut value, &|environment| environment.strengthen());
+    value
+}
+
+// replaces the environments of the functions held by the value,
+// directly or through methods, vectors, objects and payloads
+fn map_environments(value: &mut RuntimeType, map: &dyn Fn(&Environment) -> Option<Environment>) {
+    match value {
+        RuntimeType::RnFunction(function) => {
+            if let Some(environment) = map(&function.environment) {
+                function.environment = environment;
+            }
+        }
+        RuntimeType::RnStruct(structure) => map_methods(&mut structure.methods, map),
+        RuntimeType::RnInstance(instance) => {
+            // the definition is shared by the instances, so
+            // it is only copied when a method changes
+            let changes = instance
+                .definition
+                .methods
+                .iter()
+                .any(|method| map(&method.environment).is_some());
+            if changes {
+                map_methods(&mut Rc::make_mut(&mut instance.definition).methods, map);
+            }
+            map_object(&mut instance.fields, map);
+        }
+        RuntimeType::RnVariant(variant) => map_object(&mut variant.fields, map),
+        RuntimeType::RnVector(vector) => {
+            for element in vector.elements.iter_mut() {
+                map_environments(element, map);
+            }
+        }
+        RuntimeType::RnObject(object) => map_object(object, map),
+        _ => {}
+    }
+}
+
+fn map_methods(methods: &mut [RnFunction], map: &dyn Fn(&Environment) -> Option<Environment>) {
+    for method in methods.iter_mut() {
+        if let Some(environment) = map(&method.environment) {
+            method.environment = environment;
+        }
+    }
+}
+
+fn map_object(object: &mut RnObject, map: &dyn Fn(&Environment) -> Option<Environment>) {
+    for (_, value) in object.properties.iter_mut() {
+        map_environments(value, map);
+    }
+}
+
+#[derive(Debug)]
 pub struct Scope {
     vars: HashMap<String, RuntimeType>,
+    constants: HashSet<String>,
     pub invoker: ScopeInvoker,
 }
 
 impl Scope {
     fn new(invoker: ScopeInvoker) -> Scope {
+        LIVE_SCOPES.with(|count| count.set(count.get() + 1));
         Scope {
             vars: HashMap::new(),
+            constants: HashSet::new(),
             invoker,
         }
     }
 
-    fn add(&mut self, identifier: String, value: RuntimeType) -> bool {
+    fn add(&mut self, identifier: String, value: RuntimeType) -> Result<(), EgoError> {
         if self.vars.contains_key(&identifier) {
-            error::throw(
+            return Err(EgoError::new(
                 ErrorType::ReferenceError,
                 format!("Cannot redeclare '{identifier}' in the scope").as_str(),
                 None,
-            );
+            ));
         }
 
-        match self.vars.insert(identifier.clone(), value) {
-            Some(_) => {
-                error::throw(
-                    ErrorType::ReferenceError,
-                    format!("Cannot redeclare '{identifier}' in the scope").as_str(),
-                    None,
-                );
-                std::process::exit(1)
-            }
-            _ => true,
-        }
+        self.vars.insert(identifier, value);
+        Ok(())
     }
 
     fn set(&mut self, identifier: String, value: RuntimeType) -> bool {
@@ -136,3 +402,9 @@ impl Scope {
         self.vars.get(identifier)
     }
 }
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        LIVE_SCOPES.with(|count| count.set(count.get() - 1));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     ast::{lex, Module},
     compiler::Compiler,
-    core::logs::get_log_history,
+    core::{error::EgoError, logs::get_log_history},
     log,
     runtime::Interpreter,
 };
@@ -10,11 +10,26 @@ pub fn run_ego(code: String, vm: bool) -> Vec<String> {
     log!("Executing ego:");
     log!("  '- code: {}", code);
     log!("  '- vm: {}", vm);
-    let tokens = lex(code);
+
+    // errors are reported in the logs instead
+    // of ending the playground process
+    if let Err(errors) = exec(code, vm) {
+        for e in errors {
+            e.report();
+        }
+    }
+    get_log_history()
+}
+
+fn exec(code: String, vm: bool) -> Result<(), Vec<EgoError>> {
+    let tokens = lex(code, "fileless").map_err(|e| vec![e])?;
     log!(" > Generated tokens");
 
     let mut module = Module::new("fileless".to_string(), tokens);
-    let ast = module.parse();
+    let (ast, errors) = module.parse_recovering();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
     log!(" > Generated ast");
     log!(
         " > Running on {}",
@@ -23,13 +38,13 @@ pub fn run_ego(code: String, vm: bool) -> Vec<String> {
     log!("-------------------");
 
     if vm {
-        let bytecode = Compiler::gen_bytecode(ast);
+        let bytecode = Compiler::gen_bytecode(ast).map_err(|e| vec![e])?;
         let mut vm = self_vm::vm::Vm::new(bytecode);
         vm.run();
-        vec!["Logs with executions are not implemented yet".to_string()]
+        log!("Logs with executions are not implemented yet");
     } else {
-        let mut interpreter = Interpreter::new(ast.clone());
-        interpreter.exec(false);
-        get_log_history()
+        let mut interpreter = Interpreter::new(ast);
+        interpreter.exec(false).map_err(|e| vec![e])?;
     }
+    Ok(())
 }
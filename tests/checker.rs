@@ -0,0 +1,186 @@
+use ego::{EgoError, Engine, ErrorType};
+
+fn check(code: &str) -> Vec<EgoError> {
+    Engine::new().check(code)
+}
+
+fn messages(code: &str) -> Vec<String> {
+    check(code)
+        .into_iter()
+        .map(|e| {
+            assert_eq!(e.error_type, ErrorType::TypeError, "{e}");
+            e.message
+        })
+        .collect()
+}
+
+#[test]
+fn well_typed_module_has_no_errors() {
+    let errors = check(
+        r#"
+        fn fib(n: number): number {
+          if n < 2 {
+            return n
+          }
+          return fib(n - 1) + fib(n - 2)
+        }
+
+        let total = 0
+        for i in 0..10 {
+          total = total + fib(i)
+        }
+        total = "done"
+
+        let describe = fn (value): string {
+          return "value: " + value
+        }
+        let text: string = describe(total)
+        let flag: bool = !text
+        print(text, flag)
+        "#,
+    );
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn inferred_types_flow_into_annotations() {
+    let errors = messages(
+        r#"
+        let name = "ego"
+        let count: number = name
+
+        fn greet() {
+          return "Hi"
+        }
+        let greeting: number = greet()
+        let size: string = 2 * 3
+        "#,
+    );
+    assert_eq!(
+        errors,
+        [
+            "Annotation of type 'number' differs from assigned 'string' value",
+            "Annotation of type 'number' differs from assigned 'string' value",
+            "Annotation of type 'string' differs from assigned 'number' value",
+        ]
+    );
+}
+
+#[test]
+fn reassignments_respect_annotations() {
+    let errors = check(
+        r#"
+        let count: number = 0
+        count = "many"
+        "#,
+    );
+    assert_eq!(errors.len(), 1);
+    let span = errors[0].span.clone().expect("the error has a span");
+    assert_eq!((span.line, span.col), (3, 17));
+}
+
+#[test]
+fn calls_are_checked_against_parameters() {
+    let errors = messages(
+        r#"
+        fn add(a: number, b: number): number {
+          return a + b
+        }
+        let sum: string = add(1, 2)
+        add("1", 2)
+        add(1)
+        "#,
+    );
+    assert_eq!(
+        errors,
+        [
+            "Annotation of type 'string' differs from assigned 'number' value",
+            "Parameter 'a' of 'add' expects a value of type 'number' but got 'string'",
+            "Parameter 'b' of 'add' expects a value of type 'number' but got 'nothing'",
+        ]
+    );
+}
+
+#[test]
+fn returns_are_checked_against_the_return_type() {
+    let errors = messages(
+        r#"
+        fn sign(n): number {
+          if n > 0 {
+            return 1
+          } else if n < 0 {
+            return -1
+          } else {
+            return 0
+          }
+        }
+
+        fn name(n): string {
+          if n {
+            return 1
+          }
+        }
+        "#,
+    );
+    assert_eq!(
+        errors,
+        [
+            "Function 'name' must return a value of type 'string' but returned 'number'",
+            "Function 'name' must return a value of type 'string' but can end without returning",
+        ]
+    );
+}
+
+#[test]
+fn infinite_loops_do_not_fall_through() {
+    let errors = messages(
+        r#"
+        fn first(n: number): number {
+          while true {
+            return n
+          }
+        }
+
+        fn labeled(n: number): number {
+          outer: while true {
+            for i in 0..n {
+              break
+            }
+            return n
+          }
+        }
+
+        fn leaves(n: number): number {
+          while true {
+            if n > 1 {
+              break
+            }
+            return n
+          }
+        }
+
+        fn leaves_outer(n: number): number {
+          outer: while true {
+            while true {
+              break outer
+            }
+          }
+        }
+        "#,
+    );
+    assert_eq!(
+        errors,
+        [
+            "Function 'leaves' must return a value of type 'number' but can end without returning",
+            "Function 'leaves_outer' must return a value of type 'number' but can end without returning",
+        ]
+    );
+}
+
+#[test]
+fn check_does_not_run_the_module() {
+    let mut engine = Engine::new();
+    let errors = engine.check(r#"print("side effect")"#);
+    assert!(errors.is_empty());
+    assert!(engine.take_output().is_empty());
+}
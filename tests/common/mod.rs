@@ -0,0 +1,35 @@
+// helpers shared by the integration tests, each test
+// crate only uses some of them
+#![allow(dead_code)]
+
+use ego::{EgoError, Engine, Value};
+
+pub fn eval(code: &str) -> Value {
+    let mut engine = Engine::new();
+    match engine.eval(code) {
+        Ok(value) => value,
+        Err(e) => panic!("unexpected error: {e}"),
+    }
+}
+
+pub fn eval_number(code: &str) -> f64 {
+    match eval(code) {
+        Value::Number(n) => n,
+        value => panic!("expected a number but got '{value}'"),
+    }
+}
+
+pub fn eval_string(code: &str) -> String {
+    match eval(code) {
+        Value::String(s) => s,
+        value => panic!("expected a string but got '{value}'"),
+    }
+}
+
+pub fn eval_err(code: &str) -> EgoError {
+    let mut engine = Engine::new();
+    match engine.eval(code) {
+        Ok(value) => panic!("expected an error but got '{value}'"),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,28 @@
+use ego::{gen_bytecode, ErrorType};
+
+#[test]
+fn printing_number_literals_compiles() {
+    let bytecode = gen_bytecode("print(1, -2)".to_string()).unwrap();
+    assert!(!bytecode.is_empty());
+}
+
+#[test]
+fn unary_arguments_are_rejected() {
+    for code in ["let x = 1\nprint(-x)", "let done = false\nprint(!done)"] {
+        let error = gen_bytecode(code.to_string()).unwrap_err();
+        assert_eq!(error.error_type, ErrorType::CompilationError);
+    }
+}
+
+#[test]
+fn logical_operators_are_rejected() {
+    for code in [
+        "print(1 && 2)",
+        "let ready = true\nready || print(1)",
+        "let x = true\nlet y = false\nlet a = x && y",
+        "fn f(n) {\n return n > 0 || n < -1\n}",
+    ] {
+        let error = gen_bytecode(code.to_string()).unwrap_err();
+        assert_eq!(error.error_type, ErrorType::CompilationError);
+    }
+}
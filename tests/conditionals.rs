@@ -0,0 +1,173 @@
+mod common;
+
+use common::{eval, eval_number, eval_string};
+use ego::{Engine, ErrorType, Value};
+
+#[test]
+fn else_if_chain() {
+    let value = eval_string(
+        r#"
+        fn size(n) {
+            if n > 10 {
+                return "big"
+            } else if n > 5 {
+                return "medium"
+            } else {
+                return "small"
+            }
+        }
+        let sizes = size(20) + size(7) + size(1)
+        sizes
+        "#,
+    );
+    assert_eq!(value, "bigmediumsmall");
+}
+
+#[test]
+fn else_if_without_else() {
+    let value = eval_string(
+        r#"
+        let result = "none"
+        if false {
+            result = "first"
+        } else if false {
+            result = "second"
+        }
+        result
+        "#,
+    );
+    assert_eq!(value, "none");
+}
+
+#[test]
+fn if_expression_value() {
+    let value = eval(
+        r#"
+        let x = if 1 > 2 { 1 } else { 2 }
+        x
+        "#,
+    );
+    assert!(matches!(value, Value::Number(n) if n == 2.0));
+}
+
+#[test]
+fn if_expression_uses_last_expression_of_the_arm() {
+    let value = eval(
+        r#"
+        let n = 4
+        let x = if n == 3 {
+            0
+        } else if n == 4 {
+            let double = n * 2
+            double + 1
+        } else {
+            0
+        }
+        x
+        "#,
+    );
+    assert!(matches!(value, Value::Number(n) if n == 9.0));
+}
+
+#[test]
+fn if_expression_without_else_is_nothing() {
+    let value = eval(
+        r#"
+        let x = if false { 1 }
+        x
+        "#,
+    );
+    assert!(matches!(value, Value::Nothing));
+}
+
+#[test]
+fn return_inside_if_expression_is_an_error() {
+    let mut engine = Engine::new();
+    let error = engine
+        .eval(
+            r#"
+            fn f() {
+                let x = if true { return 1 } else { 2 }
+            }
+            f()
+            "#,
+        )
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::SyntaxError);
+}
+
+#[test]
+fn jumps_leaving_an_if_expression_are_rejected_by_check() {
+    let engine = Engine::new();
+    for source in [
+        "fn f() {\n let x = if true { return 1 } else { 2 }\n}",
+        "while true {\n let x = if true { 1 } else { break }\n}",
+        "for i in 0..3 {\n let x = if true { if i > 1 { continue } 1 } else { 2 }\n}",
+        "outer: while true {\n let x = if true { while true { break outer } } else { 2 }\n}",
+    ] {
+        let errors = engine.check(source);
+        assert_eq!(errors.len(), 1, "{source}");
+        assert_eq!(errors[0].error_type, ErrorType::SyntaxError);
+        assert_eq!(errors[0].span.as_ref().unwrap().line, 2, "{source}");
+    }
+}
+
+#[test]
+fn jumps_staying_inside_an_if_expression_are_valid() {
+    let value = eval_number(
+        r#"
+        let total = if true {
+            let sum = 0
+            inner: for i in 0..5 {
+                while true {
+                    if i == 3 {
+                        continue inner
+                    }
+                    break
+                }
+                sum = sum + i
+            }
+            fn double(n) {
+                return n * 2
+            }
+            double(sum)
+        } else {
+            0
+        }
+        total
+        "#,
+    );
+    assert_eq!(value, 14.0);
+}
+
+#[test]
+fn if_expression_arms_continue_after_members_and_calls() {
+    let value = eval_number(
+        r#"
+        struct Point { x: number }
+        fn f(n) {
+            return n
+        }
+        let p = Point { x: 1 }
+        let xs = [5]
+        let a = if true { p.x + 1 } else { 2 }
+        let b = if true { f(1) * 2 } else { 0 }
+        let c = if true { xs[0] + 10 } else { 0 }
+        a + b + c
+        "#,
+    );
+    assert_eq!(value, 19.0);
+}
+
+#[test]
+fn statement_starting_with_a_call_keeps_the_operator() {
+    let value = eval_number(
+        r#"
+        fn f(n) {
+            return n
+        }
+        f(1) + 2
+        "#,
+    );
+    assert_eq!(value, 3.0);
+}
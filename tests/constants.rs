@@ -0,0 +1,59 @@
+mod common;
+
+use common::eval_err;
+use ego::{Engine, ErrorType, Value};
+
+#[test]
+fn reassignment_is_rejected_by_the_parser() {
+    let error = eval_err(
+        r#"
+        const a = 1
+        a = 2
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::ConstantReassignmentError);
+}
+
+#[test]
+fn member_assignment_is_rejected_by_the_parser() {
+    let error = eval_err(
+        r#"
+        const v = [1, 2]
+        v[0] = 3
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::ConstantReassignmentError);
+}
+
+#[test]
+fn reassignment_is_rejected_at_runtime() {
+    // the function is parsed before the constant is declared
+    let error = eval_err(
+        r#"
+        fn bump() {
+            limit = limit + 1
+        }
+        const limit = 10
+        bump()
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::ConstantReassignmentError);
+}
+
+#[test]
+fn shadowing_a_constant_is_allowed() {
+    let mut engine = Engine::new();
+    let value = engine
+        .eval(
+            r#"
+            const a = 1
+            fn f(a) {
+                a = a + 1
+                return a
+            }
+            f(a)
+            "#,
+        )
+        .unwrap();
+    assert!(matches!(value, Value::Number(n) if n == 2.0));
+}
@@ -0,0 +1,171 @@
+use ego::{EgoError, Engine, ErrorType, Value};
+
+#[test]
+fn globals_are_kept_between_evaluations() {
+    let mut engine = Engine::new();
+    engine.eval("let count = 1").unwrap();
+    engine.eval("count = count + 1").unwrap();
+
+    let value = engine.eval("count").unwrap();
+    assert!(matches!(value, Value::Number(n) if n == 2.0));
+}
+
+#[test]
+fn call_runs_functions_defined_by_the_code() {
+    let mut engine = Engine::new();
+    engine
+        .eval(
+            r#"
+            fn greet(name) {
+                return "Hi " + name
+            }
+            "#,
+        )
+        .unwrap();
+
+    let value = engine
+        .call("greet", vec![Value::String("ego".to_string())])
+        .unwrap();
+    assert!(matches!(value, Value::String(s) if s == "Hi ego"));
+
+    let error = engine.call("missing", vec![]).unwrap_err();
+    assert_eq!(error.error_type, ErrorType::ReferenceError);
+}
+
+#[test]
+fn globals_can_be_read_and_written_from_rust() {
+    let mut engine = Engine::new();
+    engine.set_global("limit", Value::Number(3.0));
+    engine.eval("let doubled = limit * 2").unwrap();
+
+    assert!(matches!(engine.get_global("doubled"), Some(Value::Number(n)) if n == 6.0));
+    assert!(engine.get_global("missing").is_none());
+}
+
+#[test]
+fn native_functions_are_callable_from_ego() {
+    let mut engine = Engine::new();
+    engine.register_native("add", Some(2), |args| match args {
+        [Value::Number(a), Value::Number(b)] => Ok(Value::Number(a + b)),
+        _ => Err(EgoError::new(
+            ErrorType::TypeError,
+            "add expects two numbers",
+            None,
+        )),
+    });
+
+    let value = engine.eval("add(1, 2)").unwrap();
+    assert!(matches!(value, Value::Number(n) if n == 3.0));
+
+    let error = engine.eval(r#"add(1, "2")"#).unwrap_err();
+    assert_eq!(error.error_type, ErrorType::TypeError);
+}
+
+#[test]
+fn registered_natives_are_listed() {
+    let mut engine = Engine::new();
+    assert_eq!(engine.natives(), vec!["print", "type"]);
+
+    engine.register_native("now", Some(0), |_| Ok(Value::Number(0.0)));
+    engine.register_native("print", None, |_| Ok(Value::Nothing));
+    assert_eq!(engine.natives(), vec!["print", "type", "now"]);
+}
+
+#[test]
+fn print_is_captured() {
+    let mut engine = Engine::new();
+    engine.eval(r#"print("a", 1)"#).unwrap();
+    engine.eval(r#"print("b")"#).unwrap();
+
+    assert_eq!(engine.take_output(), vec!["a 1", "b"]);
+    assert!(engine.take_output().is_empty());
+}
+
+#[test]
+fn check_reports_type_errors_without_running() {
+    let mut engine = Engine::new();
+    let errors = engine.check(
+        r#"
+        let n: number = "one"
+        print(n)
+        "#,
+    );
+    assert_eq!(errors.len(), 1);
+    assert!(engine.take_output().is_empty());
+}
+
+#[test]
+fn instances_keep_their_struct() {
+    let mut engine = Engine::new();
+    engine
+        .eval(
+            r#"
+            struct Point { x, y }
+            impl Point {
+              fn sum(self) {
+                return self.x + self.y
+              }
+            }
+            fn sum(p) {
+              return p.sum()
+            }
+            "#,
+        )
+        .unwrap();
+
+    let point = engine.eval("Point { x: 1, y: 2 }").unwrap();
+    match &point {
+        Value::Instance(instance) => {
+            assert_eq!(instance.name(), "Point");
+            assert_eq!(instance.fields().len(), 2);
+            assert!(
+                matches!(instance.fields()[1], (ref key, Value::Number(n)) if key == "y" && n == 2.0)
+            );
+        }
+        other => panic!("expected an instance but got {}", other.type_name()),
+    }
+
+    // handed back it still has its methods
+    let value = engine.call("sum", vec![point]).unwrap();
+    assert!(matches!(value, Value::Number(n) if n == 3.0));
+}
+
+#[test]
+fn variants_and_definitions_round_trip() {
+    let mut engine = Engine::new();
+    engine
+        .eval(
+            r#"
+            enum Shape { Circle(r), Empty }
+            struct Unit {}
+            fn describe(value) {
+              return match value {
+                Shape.Circle(r) => "circle {r}",
+                _ => "{type(value)}",
+              }
+            }
+            "#,
+        )
+        .unwrap();
+
+    let circle = engine.eval("Shape.Circle(2)").unwrap();
+    match &circle {
+        Value::Variant(variant) => {
+            assert_eq!(variant.enum_name(), "Shape");
+            assert_eq!(variant.name(), "Circle");
+            assert!(matches!(variant.fields(), [(_, Value::Number(n))] if *n == 2.0));
+        }
+        other => panic!("expected a variant but got {}", other.type_name()),
+    }
+    let value = engine.call("describe", vec![circle]).unwrap();
+    assert!(matches!(value, Value::String(s) if s == "circle 2"));
+
+    let shape = engine.eval("Shape").unwrap();
+    assert!(matches!(&shape, Value::Enum(d) if d.name() == "Shape"));
+    let unit = engine.eval("Unit").unwrap();
+    assert!(matches!(&unit, Value::Struct(d) if d.name() == "Unit"));
+    for (definition, expected) in [(shape, "enum"), (unit, "struct")] {
+        let value = engine.call("describe", vec![definition]).unwrap();
+        assert!(matches!(value, Value::String(s) if s == expected));
+    }
+}
@@ -0,0 +1,94 @@
+use ego::{EgoError, Engine, ErrorType};
+
+#[test]
+fn errors_can_be_sent_across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<EgoError>();
+}
+
+#[test]
+fn every_syntax_error_is_reported_with_its_span() {
+    let engine = Engine::new();
+    let errors = engine.check(
+        "let a = 1\n\
+         let b = )\n\
+         print(a)\n\
+         let c = ]\n\
+         print(c)",
+    );
+
+    let positions: Vec<(usize, usize)> = errors
+        .iter()
+        .map(|e| {
+            let span = e.span.as_ref().expect("syntax errors have a span");
+            (span.line, span.col)
+        })
+        .collect();
+    assert_eq!(positions, vec![(2, 9), (4, 9)]);
+    assert!(errors
+        .iter()
+        .all(|e| e.error_type == ErrorType::SyntaxError));
+}
+
+#[test]
+fn incomplete_imports_are_errors() {
+    let mut engine = Engine::new();
+    for source in ["import foo.", "import std/math."] {
+        let error = engine.eval(source).unwrap_err();
+        assert_eq!(error.error_type, ErrorType::ParsingError, "{source}");
+    }
+}
+
+// line and column of every error found by check
+fn positions(source: &str) -> Vec<(usize, usize)> {
+    Engine::new()
+        .check(source)
+        .iter()
+        .map(|e| {
+            let span = e.span.as_ref().expect("syntax errors have a span");
+            (span.line, span.col)
+        })
+        .collect()
+}
+
+#[test]
+fn unexpected_tokens_are_not_skipped() {
+    assert_eq!(positions("print(1))"), vec![(1, 9)]);
+    assert_eq!(positions("= 5"), vec![(1, 1)]);
+    assert_eq!(positions("} } ]\nprint(1)"), vec![(1, 1), (1, 3)]);
+    assert_eq!(positions("print(1 2)"), vec![(1, 6)]);
+}
+
+#[test]
+fn recovery_stops_at_the_next_line() {
+    assert_eq!(
+        positions("let = 5\nprint(\"a\"\nlet y ="),
+        vec![(1, 7), (2, 6), (3, 7)]
+    );
+}
+
+#[test]
+fn trace_lists_the_calls_innermost_first() {
+    let error = Engine::new()
+        .eval(
+            "fn inner() {\n\
+             return 1 / missing\n\
+             }\n\
+             fn outer() {\n\
+             return inner()\n\
+             }\n\
+             outer()",
+        )
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::ReferenceError);
+
+    let frames: Vec<(&str, usize, usize)> = error
+        .trace
+        .iter()
+        .map(|frame| {
+            let span = frame.span.as_ref().expect("calls have a span");
+            (frame.function.as_str(), span.line, span.col)
+        })
+        .collect();
+    assert_eq!(frames, vec![("inner", 5, 8), ("outer", 7, 1)]);
+}
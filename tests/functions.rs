@@ -0,0 +1,123 @@
+mod common;
+
+use common::{eval, eval_err};
+use ego::{Engine, ErrorType, Value};
+
+#[test]
+fn annotated_parameters_and_return_type() {
+    let value = eval(
+        r#"
+        fn add(a: number, b: number): number {
+          return a + b
+        }
+        let shout = fn (s: string): string { return s + "!" }
+        shout("sum " + add(1, 2))
+        "#,
+    );
+    assert!(matches!(value, Value::String(s) if s == "sum 3!"));
+}
+
+#[test]
+fn parameter_mismatch_names_the_parameter() {
+    let error = eval_err(
+        r#"
+        fn add(a: number, b: number) {
+          return a + b
+        }
+        add(1, "2")
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::TypeError);
+    assert!(error.message.contains("'b'"), "{}", error.message);
+}
+
+#[test]
+fn missing_arguments_are_nothing() {
+    let error = eval_err("fn f(flag: bool) {}\nf()");
+    assert_eq!(error.error_type, ErrorType::TypeError);
+
+    let value = eval("fn g(x: nothing) { return x }\ng()");
+    assert!(matches!(value, Value::Nothing));
+}
+
+#[test]
+fn return_type_mismatch() {
+    let error = eval_err(
+        r#"
+        fn half(n): number {
+          if n == 0 {
+            return "zero"
+          }
+          return n / 2
+        }
+        half(4)
+        half(0)
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::TypeError);
+
+    // falling off the end returns nothing
+    let error = eval_err("fn f(): number {}\nf()");
+    assert_eq!(error.error_type, ErrorType::TypeError);
+}
+
+#[test]
+fn annotations_are_checked_for_host_calls() {
+    let mut engine = Engine::new();
+    engine
+        .eval("fn double(n: number): number { return n * 2 }")
+        .unwrap();
+    let error = engine
+        .call("double", vec![Value::String("2".to_string())])
+        .unwrap_err();
+    assert_eq!(error.error_type, ErrorType::TypeError);
+    assert!(matches!(
+        engine.call("double", vec![Value::Number(2.0)]),
+        Ok(Value::Number(n)) if n == 4.0
+    ));
+}
+
+// debug builds use a lot of native stack per ego call
+fn with_big_stack<F: FnOnce() + Send + 'static>(f: F) {
+    std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn deep_recursion() {
+    with_big_stack(|| {
+        let value = eval(
+            r#"
+            fn count(n) {
+                if n == 0 {
+                    return 0
+                }
+                return count(n - 1) + 1
+            }
+            count(900)
+            "#,
+        );
+        assert!(matches!(value, Value::Number(n) if n == 900.0));
+    });
+}
+
+#[test]
+fn unbounded_recursion_is_a_range_error() {
+    with_big_stack(|| {
+        let error = eval_err(
+            r#"
+            fn forever(n) {
+                return forever(n + 1)
+            }
+            forever(0)
+            "#,
+        );
+        assert_eq!(error.error_type, ErrorType::RangeError);
+        assert_eq!(error.trace.len(), 1000);
+        assert!(error.trace.iter().all(|frame| frame.function == "forever"));
+    });
+}
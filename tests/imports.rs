@@ -0,0 +1,98 @@
+use std::{fs, path::PathBuf, process::Command};
+
+// writes the modules to a fresh directory and runs main.ego
+// with the ego binary, returning whether it succeeded and
+// everything it printed
+fn run_modules(name: &str, modules: &[(&str, &str)]) -> (bool, String) {
+    let dir: PathBuf = std::env::temp_dir().join(format!("ego-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    for (path, source) in modules {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, source).unwrap();
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ego"))
+        .arg("run")
+        .arg(dir.join("main.ego"))
+        .output()
+        .unwrap();
+    let _ = fs::remove_dir_all(&dir);
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn imported_members_are_bound() {
+    let (success, output) = run_modules(
+        "members",
+        &[
+            ("main.ego", "import utils/math.[double]\nprint(double(21))"),
+            ("utils/math.ego", "fn double(n) {\n return n * 2\n}"),
+        ],
+    );
+    assert!(success, "{output}");
+    assert_eq!(output.trim(), "42");
+}
+
+#[test]
+fn modules_are_executed_once() {
+    let (success, output) = run_modules(
+        "cache",
+        &[
+            (
+                "main.ego",
+                "import counter.[value]\nimport other.[twice]\nprint(value, twice)",
+            ),
+            ("other.ego", "import counter.[value]\nlet twice = value * 2"),
+            ("counter.ego", "print(\"loaded\")\nlet value = 1"),
+        ],
+    );
+    assert!(success, "{output}");
+    assert_eq!(output.trim(), "loaded\n1 2");
+}
+
+#[test]
+fn import_cycles_are_errors() {
+    let (success, output) = run_modules(
+        "cycle",
+        &[
+            ("main.ego", "import a.[x]"),
+            ("a.ego", "import b.[y]\nlet x = 1"),
+            ("b.ego", "import a.[x]\nlet y = 2"),
+        ],
+    );
+    assert!(!success);
+    assert!(
+        output.contains("Import error: Import cycle detected"),
+        "{output}"
+    );
+}
+
+#[test]
+fn missing_modules_are_import_errors() {
+    let (success, output) = run_modules("missing", &[("main.ego", "import nowhere.[x]")]);
+    assert!(!success);
+    assert!(
+        output.contains("Import error: Cannot find module 'nowhere'"),
+        "{output}"
+    );
+}
+
+#[test]
+fn imported_structs_can_be_constructed() {
+    let (success, output) = run_modules(
+        "structs",
+        &[
+            (
+                "main.ego",
+                "import shapes.[Q, Empty]\nlet q = Q { v: 2 }\nlet e = Empty {}\nprint(q.v, type(e))",
+            ),
+            ("shapes.ego", "struct Q {\n v\n}\nstruct Empty {}"),
+        ],
+    );
+    assert!(success, "{output}");
+    assert_eq!(output.trim(), "2 Empty");
+}
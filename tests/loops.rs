@@ -0,0 +1,199 @@
+mod common;
+
+use common::{eval_err, eval_number};
+use ego::{Engine, ErrorType};
+
+#[test]
+fn for_over_range() {
+    let value = eval_number(
+        r#"
+        let total = 0
+        for i in 0..5 {
+            total = total + i
+        }
+        total
+        "#,
+    );
+    assert_eq!(value, 10.0);
+}
+
+#[test]
+fn for_over_vector() {
+    let value = eval_number(
+        r#"
+        let total = 0
+        for x in [1, 2, 3] {
+            total = total + x
+        }
+        total
+        "#,
+    );
+    assert_eq!(value, 6.0);
+}
+
+#[test]
+fn for_over_string() {
+    let mut engine = Engine::new();
+    engine
+        .eval(
+            r#"
+            for c in "ego" {
+                print(c)
+            }
+            "#,
+        )
+        .unwrap();
+    assert_eq!(engine.take_output(), vec!["e", "g", "o"]);
+}
+
+#[test]
+fn break_and_continue() {
+    let value = eval_number(
+        r#"
+        let total = 0
+        for i in 0..10 {
+            if i == 2 {
+                continue
+            }
+            if i == 5 {
+                break
+            }
+            total = total + i
+        }
+        total
+        "#,
+    );
+    assert_eq!(value, 8.0);
+}
+
+#[test]
+fn break_inside_while_if() {
+    let value = eval_number(
+        r#"
+        let i = 0
+        while true {
+            i = i + 1
+            if i == 3 {
+                break
+            }
+        }
+        i
+        "#,
+    );
+    assert_eq!(value, 3.0);
+}
+
+#[test]
+fn return_from_inside_loop() {
+    let value = eval_number(
+        r#"
+        fn find(xs, target) {
+            for i in 0..3 {
+                if xs[i] == target {
+                    return i
+                }
+            }
+            return 10
+        }
+        find([4, 5, 6], 6)
+        "#,
+    );
+    assert_eq!(value, 2.0);
+}
+
+#[test]
+fn loop_item_does_not_leak() {
+    let error = eval_err(
+        r#"
+        for x in [1] {}
+        x
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::ReferenceError);
+}
+
+#[test]
+fn iterating_a_number_is_an_error() {
+    let error = eval_err(
+        r#"
+        for x in 3 {}
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::TypeError);
+}
+
+#[test]
+fn break_outside_a_loop_is_an_error() {
+    let error = eval_err(
+        r#"
+        fn f() {
+            break
+        }
+        f()
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::SyntaxError);
+}
+
+#[test]
+fn top_level_break_and_continue_are_errors() {
+    for source in ["break", "print(1)\ncontinue\nprint(2)"] {
+        let errors = Engine::new().check(source);
+        assert_eq!(errors.len(), 1, "{source}");
+        assert_eq!(errors[0].error_type, ErrorType::SyntaxError);
+        assert_eq!(
+            errors[0].message,
+            "'break' and 'continue' are only valid inside a loop"
+        );
+    }
+}
+
+#[test]
+fn labeled_break_leaves_outer_loop() {
+    let value = eval_number(
+        r#"
+        let count = 0
+        outer: for i in 0..3 {
+            while true {
+                count = count + 1
+                break outer
+            }
+        }
+        count
+        "#,
+    );
+    assert_eq!(value, 1.0);
+}
+
+#[test]
+fn labeled_continue_skips_outer_iteration() {
+    let value = eval_number(
+        r#"
+        let count = 0
+        outer: for i in 0..3 {
+            for j in 0..3 {
+                if j == 1 {
+                    continue outer
+                }
+                count = count + 1
+            }
+        }
+        count
+        "#,
+    );
+    assert_eq!(value, 3.0);
+}
+
+#[test]
+fn unknown_label_is_an_error() {
+    let error = eval_err(
+        r#"
+        outer: while true {
+            fn f() {
+                break outer
+            }
+        }
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::SyntaxError);
+}
@@ -0,0 +1,180 @@
+mod common;
+
+use common::{eval, eval_err, eval_string};
+use ego::{Engine, ErrorType, Value};
+
+const STATE: &str = r#"
+    enum State {
+      Idle,
+      Running(speed: number),
+      Done(code, message)
+    }
+
+    fn describe(state) {
+      return match state {
+        State.Idle => "idle",
+        State.Running(0) => "stalled",
+        State.Running(speed) => "running at {speed}",
+        State.Done(0, _) => "ok",
+        State.Done(code, message) => {
+          let text = "failed with {code}: {message}"
+          text
+        }
+      }
+    }
+"#;
+
+#[test]
+fn variants_are_matched_in_order() {
+    let value = eval_string(&format!(
+        r#"{STATE}
+        let states = [State.Idle, State.Running(0), State.Running(3), State.Done(0, "")]
+        let result = ""
+        for state in states {{
+          result = result + describe(state) + ", "
+        }}
+        result + describe(State.Done(2, "oops"))
+        "#
+    ));
+    assert_eq!(
+        value,
+        "idle, stalled, running at 3, ok, failed with 2: oops"
+    );
+}
+
+#[test]
+fn variants_have_type_fields_and_equality() {
+    let value = eval_string(&format!(
+        r#"{STATE}
+        let done = State.Done(1, "x")
+        "{{done}} {{type(done)}} {{type(State)}} {{done.code}} {{done == State.Done(1, "x")}} {{done == State.Idle}}"
+        "#
+    ));
+    assert_eq!(value, r#"State.Done(1, "x") State enum 1 true false"#);
+}
+
+#[test]
+fn literal_wildcard_and_binding_patterns() {
+    let value = eval_string(
+        r#"
+        fn name(n) {
+          return match n {
+            0 => "zero",
+            -1 => "minus one",
+            "one" => "a string",
+            true => "a bool",
+            nothing => "nothing",
+            other => "other {other}"
+          }
+        }
+        "{name(0)}, {name(-1)}, {name("one")}, {name(true)}, {name(nothing)}, {name(7)}"
+        "#,
+    );
+    assert_eq!(value, "zero, minus one, a string, a bool, nothing, other 7");
+
+    let value = eval_string(r#"match [1, 2] { _ => "anything" }"#);
+    assert_eq!(value, "anything");
+}
+
+#[test]
+fn variant_patterns_without_payload_match_any_payload() {
+    let value = eval(&format!(
+        "{STATE}\nmatch State.Running(9) {{ State.Running => true, _ => false }}"
+    ));
+    assert!(matches!(value, Value::Boolean(true)));
+}
+
+#[test]
+fn match_statements_can_return_and_leave_loops() {
+    let value = eval_string(
+        r#"
+        fn first_negative(numbers) {
+          for n in numbers {
+            match n < 0 {
+              true => { return n }
+              false => {}
+            }
+          }
+          return nothing
+        }
+
+        let seen = ""
+        for i in 0..10 {
+          match i {
+            1 => { continue }
+            4 => { break }
+            _ => { seen = seen + i }
+          }
+        }
+        "{first_negative([3, -2, -5])} {seen}"
+        "#,
+    );
+    assert_eq!(value, "-2 023");
+}
+
+#[test]
+fn jumps_leaving_a_match_expression_are_rejected_by_check() {
+    let engine = Engine::new();
+    for source in [
+        "fn f(n) {\n let x = match n { 1 => { return 1 }, _ => 2 }\n}",
+        "while true {\n print(match 1 { _ => { break } })\n}",
+        "for i in 0..3 {\n let x = match i { 1 => { if true { continue } }, _ => 0 }\n}",
+    ] {
+        let errors = engine.check(source);
+        assert_eq!(errors.len(), 1, "{source}");
+        assert_eq!(errors[0].error_type, ErrorType::SyntaxError);
+        assert_eq!(errors[0].span.as_ref().unwrap().line, 2, "{source}");
+    }
+
+    // loops and functions inside the arm keep their jumps
+    let errors = engine.check(
+        "let x = match 1 {\n 1 => { for i in 0..2 { break } fn f() { return 1 } f() },\n _ => 0\n}",
+    );
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn unmatched_values_are_errors() {
+    let error = eval_err(&format!(
+        "{STATE}\nmatch State.Running(2) {{ State.Idle => 1 }}"
+    ));
+    assert_eq!(error.error_type, ErrorType::MatchError);
+    assert_eq!(error.message, "No arm matches the value 'State.Running(2)'");
+
+    let error = eval_err("match 3 { 1 => 1, 2 => 2 }");
+    assert_eq!(error.error_type, ErrorType::MatchError);
+}
+
+#[test]
+fn variants_are_checked_when_created() {
+    let error = eval_err(&format!("{STATE}\nState.Running(\"fast\")"));
+    assert_eq!(error.error_type, ErrorType::TypeError);
+    assert_eq!(
+        error.message,
+        "Field 'speed' of 'State.Running' expects a value of type 'number' but got 'string'"
+    );
+
+    let error = eval_err(&format!("{STATE}\nState.Done(1)"));
+    assert_eq!(
+        error.message,
+        "Variant 'State.Done' expects 2 values but got 1"
+    );
+
+    let error = eval_err(&format!("{STATE}\nState.Stopped"));
+    assert_eq!(error.message, "Enum 'State' has no variant named 'Stopped'");
+}
+
+#[test]
+fn check_reports_variant_mismatches() {
+    let errors = Engine::new().check(&format!(
+        "{STATE}\nlet s = State.Running(\"fast\")\nmatch s {{ State.Done(code) => 1, _ => 2 }}"
+    ));
+    let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        [
+            "Field 'speed' of 'State.Running' expects a value of type 'number' but got 'string'",
+            "Variant 'State.Done' has 2 fields but the pattern matches 1",
+        ]
+    );
+}
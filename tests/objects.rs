@@ -0,0 +1,64 @@
+mod common;
+
+use common::{eval, eval_err, eval_string};
+use ego::{ErrorType, Value};
+
+#[test]
+fn member_access() {
+    let value = eval_string(
+        r#"
+        let user = { name: "ego", address: { city: "Madrid" } }
+        user.name + " " + user.address.city
+        "#,
+    );
+    assert_eq!(value, "ego Madrid");
+}
+
+#[test]
+fn missing_members_are_nothing() {
+    let value = eval(
+        r#"
+        let user = { name: "ego" }
+        user.age
+        "#,
+    );
+    assert!(matches!(value, Value::Nothing));
+}
+
+#[test]
+fn reading_a_member_of_a_non_object_is_an_error() {
+    let error = eval_err(
+        r#"
+        let n = 1
+        n.value
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::TypeError);
+}
+
+#[test]
+fn nested_mutation() {
+    let value = eval_string(
+        r#"
+        let user = { name: "ego", address: { city: "Madrid" }, tags: ["a"] }
+        user.address.city = "Bilbao"
+        user.address.zip = "48001"
+        user.tags[0] = "b"
+        "{user.address.city} {user.address.zip} {user.tags[0]}"
+        "#,
+    );
+    assert_eq!(value, "Bilbao 48001 b");
+}
+
+#[test]
+fn objects_are_copied_on_assignment() {
+    let value = eval_string(
+        r#"
+        let a = { n: 1 }
+        let b = a
+        b.n = 2
+        "{a.n} {b.n}"
+        "#,
+    );
+    assert_eq!(value, "1 2");
+}
@@ -0,0 +1,150 @@
+mod common;
+
+use common::{eval, eval_number};
+use ego::{Engine, ErrorType, Value};
+
+#[test]
+fn negative_literals() {
+    assert_eq!(eval_number("-5"), -5.0);
+    assert_eq!(eval_number("2 - -3"), 5.0);
+}
+
+#[test]
+fn negation_binds_tighter_than_binary_operators() {
+    let value = eval_number(
+        r#"
+        let x = 4
+        let y = -x * 2 + -(x - 1)
+        y
+        "#,
+    );
+    assert_eq!(value, -11.0);
+}
+
+#[test]
+fn negation_applies_to_members_and_calls() {
+    let value = eval_number(
+        r#"
+        fn two() {
+            return 2
+        }
+        let v = [1, 3]
+        let total = -v[1] + -two()
+        total
+        "#,
+    );
+    assert_eq!(value, -5.0);
+}
+
+#[test]
+fn logical_not() {
+    let value = eval(
+        r#"
+        let done = false
+        let result = !done == !!true
+        result
+        "#,
+    );
+    assert!(matches!(value, Value::Boolean(true)));
+}
+
+#[test]
+fn negating_a_string_is_an_error() {
+    let mut engine = Engine::new();
+    let error = engine.eval(r#"-"ego""#).unwrap_err();
+    assert_eq!(error.error_type, ErrorType::TypeError);
+}
+
+#[test]
+fn logical_operators_short_circuit() {
+    let value = eval_number(
+        r#"
+        let calls = 0
+        fn hit() {
+            calls = calls + 1
+            return true
+        }
+        false && hit()
+        true || hit()
+        true && hit()
+        false || hit()
+        calls
+        "#,
+    );
+    assert_eq!(value, 2.0);
+}
+
+#[test]
+fn logical_operators_bind_looser_than_comparisons() {
+    let value = eval(
+        r#"
+        let x = 5
+        let result = x > 1 && x < 3 || x == 5 && !false
+        result
+        "#,
+    );
+    assert!(matches!(value, Value::Boolean(true)));
+}
+
+#[test]
+fn truthiness_of_every_type() {
+    let mut engine = Engine::new();
+    engine
+        .eval(
+            r#"
+            print(0 || nothing || "" || [], -1 && "a" && [0] && {} && print)
+            "#,
+        )
+        .unwrap();
+    assert_eq!(engine.take_output(), vec!["false true"]);
+}
+
+#[test]
+fn modulo_and_integer_division_round_down() {
+    assert_eq!(eval_number("7 % 3"), 1.0);
+    assert_eq!(eval_number("-7 % 3"), 2.0);
+    assert_eq!(eval_number("7 ~/ 2"), 3.0);
+    assert_eq!(eval_number("-7 ~/ 2"), -4.0);
+}
+
+#[test]
+fn exponent_is_right_associative_and_binds_tighter() {
+    assert_eq!(eval_number("2 ** 3 ** 2"), 512.0);
+    assert_eq!(eval_number("2 * 3 ** 2"), 18.0);
+    assert_eq!(eval_number("10 - 9 % 4"), 9.0);
+}
+
+#[test]
+fn new_operators_on_non_numbers_are_nothing() {
+    let value = eval(
+        r#"
+        let values = ["a" % 2, [1] ** 2, true ~/ 1]
+        values
+        "#,
+    );
+    assert!(matches!(value, Value::Vector(v) if v.iter().all(|v| matches!(v, Value::Nothing))));
+}
+
+#[test]
+fn power_binds_tighter_than_negation() {
+    let value = eval_number(
+        r#"
+        let x = 3
+        let a = -2 ** 2
+        let b = -x ** 2
+        let c = 2 ** -1
+        a + b + c
+        "#,
+    );
+    assert_eq!(value, -12.5);
+}
+
+#[test]
+fn integer_division_and_remainder_by_zero_are_errors() {
+    let mut engine = Engine::new();
+    let error = engine.eval("5 ~/ 0").unwrap_err();
+    assert_eq!(error.error_type, ErrorType::ArithmeticError);
+
+    let error = engine.eval("5 % 0").unwrap_err();
+    assert_eq!(error.error_type, ErrorType::ArithmeticError);
+}
@@ -0,0 +1,283 @@
+mod common;
+
+use common::{eval, eval_number, eval_string};
+use ego::{live_scopes, Engine, Value};
+
+#[test]
+fn function_does_not_see_caller_locals() {
+    let mut engine = Engine::new();
+    let result = engine.eval(
+        r#"
+        fn peek() {
+            return secret
+        }
+        fn caller() {
+            let secret = 1
+            return peek()
+        }
+        caller()
+        "#,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn free_variables_resolve_in_definition_scope() {
+    let value = eval_string(
+        r#"
+        let x = "global"
+        fn show() {
+            return x
+        }
+        fn caller() {
+            let x = "local"
+            return show()
+        }
+        caller()
+        "#,
+    );
+    assert_eq!(value, "global");
+}
+
+#[test]
+fn parameter_shadows_global() {
+    let value = eval_number(
+        r#"
+        let x = 1
+        fn f(x) {
+            return x
+        }
+        f(2)
+        "#,
+    );
+    assert_eq!(value, 2.0);
+}
+
+#[test]
+fn local_does_not_overwrite_shadowed_global() {
+    let value = eval_number(
+        r#"
+        let x = 1
+        fn f() {
+            let x = 2
+            return x
+        }
+        f()
+        x
+        "#,
+    );
+    assert_eq!(value, 1.0);
+}
+
+#[test]
+fn block_local_does_not_leak() {
+    let value = eval_number(
+        r#"
+        let x = 1
+        if true {
+            let x = 2
+        }
+        x
+        "#,
+    );
+    assert_eq!(value, 1.0);
+}
+
+#[test]
+fn assignment_updates_enclosing_variable() {
+    let value = eval_number(
+        r#"
+        let total = 0
+        fn add(n) {
+            total = total + n
+        }
+        add(2)
+        add(3)
+        total
+        "#,
+    );
+    assert_eq!(value, 5.0);
+}
+
+#[test]
+fn closure_keeps_its_own_environment() {
+    let value = eval_number(
+        r#"
+        fn make_adder(n) {
+            return fn (x) { return x + n }
+        }
+        let add_one = make_adder(1)
+        let add_ten = make_adder(10)
+        let result = add_one(1) + add_ten(1)
+        result
+        "#,
+    );
+    assert_eq!(value, 13.0);
+}
+
+#[test]
+fn closure_state_is_shared_between_calls() {
+    let value = eval_number(
+        r#"
+        fn counter() {
+            let count = 0
+            fn next() {
+                count = count + 1
+                return count
+            }
+            return next
+        }
+        let next = counter()
+        next()
+        next()
+        next()
+        "#,
+    );
+    assert_eq!(value, 3.0);
+}
+
+#[test]
+fn recursion() {
+    let value = eval_number(
+        r#"
+        fn fib(n) {
+            if n < 2 {
+                return n
+            }
+            return fib(n - 1) + fib(n - 2)
+        }
+        fib(10)
+        "#,
+    );
+    assert_eq!(value, 55.0);
+}
+
+#[test]
+fn mutual_recursion() {
+    let value = eval(
+        r#"
+        fn is_even(n) {
+            if n == 0 {
+                return true
+            }
+            return is_odd(n - 1)
+        }
+        fn is_odd(n) {
+            if n == 0 {
+                return false
+            }
+            return is_even(n - 1)
+        }
+        is_even(10)
+        "#,
+    );
+    assert!(matches!(value, Value::Boolean(true)));
+}
+
+#[test]
+fn nested_function_recursion() {
+    let value = eval_number(
+        r#"
+        fn sum_to(n) {
+            fn go(i, acc) {
+                if i > n {
+                    return acc
+                }
+                return go(i + 1, acc + i)
+            }
+            return go(1, 0)
+        }
+        sum_to(4)
+        "#,
+    );
+    assert_eq!(value, 10.0);
+}
+
+#[test]
+fn recursive_calls_have_their_own_locals() {
+    let value = eval_number(
+        r#"
+        fn depth(n) {
+            let here = n
+            if n > 0 {
+                depth(n - 1)
+            }
+            return here
+        }
+        depth(3)
+        "#,
+    );
+    assert_eq!(value, 3.0);
+}
+
+#[test]
+fn returned_function_can_still_call_itself() {
+    let value = eval_number(
+        r#"
+        fn make() {
+            fn fact(n) {
+                if n < 2 {
+                    return 1
+                }
+                return n * fact(n - 1)
+            }
+            return fact
+        }
+        let fact = make()
+        fact(5)
+        "#,
+    );
+    assert_eq!(value, 120.0);
+}
+
+#[test]
+fn finished_calls_free_their_scopes() {
+    let mut engine = Engine::new();
+    engine
+        .eval(
+            r#"
+            fn work(n) {
+                fn inner() {
+                    return n
+                }
+                let twice = fn () { return inner() * 2 }
+                struct Box { value }
+                impl Box {
+                    fn get(self) {
+                        return self.value
+                    }
+                }
+                let boxed = Box { value: twice() }
+                let all = [inner, { call: twice }, boxed]
+                return boxed.get()
+            }
+            "#,
+        )
+        .unwrap();
+
+    engine.eval("work(1)").unwrap();
+    let live = live_scopes();
+    for _ in 0..50 {
+        engine.eval("work(1)").unwrap();
+    }
+    assert_eq!(live_scopes(), live);
+}
+
+#[test]
+fn dropping_the_engine_frees_its_scopes() {
+    let live = live_scopes();
+    let mut engine = Engine::new();
+    engine
+        .eval(
+            r#"
+            fn double(n) {
+                return n * 2
+            }
+            let triple = fn (n) { return n * 3 }
+            double(triple(1))
+            "#,
+        )
+        .unwrap();
+    drop(engine);
+    assert_eq!(live_scopes(), live);
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use common::{eval, eval_err, eval_number, eval_string};
+use ego::{Engine, ErrorType, Value};
+
+#[test]
+fn math_functions() {
+    let value = eval_number(
+        r#"
+        import std/math.[floor, sqrt, pow]
+        floor(2.7) + sqrt(16) + pow(2, 3)
+        "#,
+    );
+    assert_eq!(value, 14.0);
+
+    let error = eval_err(
+        r#"
+        import std/math.[sqrt]
+        sqrt(-1)
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::RangeError);
+}
+
+#[test]
+fn seeded_random_numbers_repeat() {
+    let value = eval(
+        r#"
+        import std/math.[random, seed]
+        seed(42)
+        let first = [random(), random()]
+        seed(42)
+        let second = [random(), random()]
+        first == second && first[0] >= 0 && first[0] < 1 && first[0] != first[1]
+        "#,
+    );
+    assert!(matches!(value, Value::Boolean(true)));
+}
+
+#[test]
+fn engines_keep_their_own_random_state() {
+    let mut first = Engine::new();
+    let mut second = Engine::new();
+    for engine in [&mut first, &mut second] {
+        engine
+            .eval("import std/math.[random, seed]\nseed(7)")
+            .unwrap();
+    }
+
+    // drawing from one engine must not move the other one
+    let a = first.eval("random()").unwrap();
+    let b = second.eval("random()").unwrap();
+    assert!(matches!((a, b), (Value::Number(a), Value::Number(b)) if a == b));
+}
+
+#[test]
+fn string_functions() {
+    let value = eval_string(
+        r#"
+        import std/string.[split, trim, replace, len]
+        let parts = split("a,b,c", ",")
+        let text = replace(trim("  ego  "), "e", "E")
+        "{len(parts)} {parts[1]} {text} {len(text)}"
+        "#,
+    );
+    assert_eq!(value, "3 b Ego 3");
+
+    let error = eval_err(
+        r#"
+        import std/string.[trim]
+        trim(1)
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::TypeError);
+}
+
+#[test]
+fn io_reads_and_writes_files() {
+    let path = std::env::temp_dir().join(format!("ego-io-{}.txt", std::process::id()));
+    let path = path.display().to_string().replace('\\', "/");
+
+    let value = eval_string(&format!(
+        r#"
+        import std/io.[read_file, write_file]
+        write_file("{path}", "hello")
+        read_file("{path}")
+        "#
+    ));
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(value, "hello");
+
+    let error = eval_err(&format!(
+        r#"
+        import std/io.[read_file]
+        read_file("{path}")
+        "#
+    ));
+    assert_eq!(error.error_type, ErrorType::IoError);
+}
+
+#[test]
+fn unknown_std_modules_are_import_errors() {
+    let error = eval_err("import std/nope.[x]");
+    assert_eq!(error.error_type, ErrorType::ImportError);
+}
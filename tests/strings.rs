@@ -0,0 +1,79 @@
+mod common;
+
+use common::eval_string;
+use ego::{Engine, ErrorType, Value};
+
+#[test]
+fn escape_sequences() {
+    let value = eval_string(r#""a\tb\n\"c\" \\ \u{e9}""#);
+    assert_eq!(value, "a\tb\n\"c\" \\ é");
+}
+
+#[test]
+fn raw_strings_keep_backslashes_and_lines() {
+    let value = eval_string("`a\\n \"b\"\nc`");
+    assert_eq!(value, "a\\n \"b\"\nc");
+}
+
+#[test]
+fn literals_and_built_strings_are_equal() {
+    let mut engine = Engine::new();
+    let value = engine
+        .eval(
+            r#"
+            let built = "a" + "b"
+            let same = built == "ab"
+            same
+            "#,
+        )
+        .unwrap();
+    assert!(matches!(value, Value::Boolean(true)));
+}
+
+#[test]
+fn empty_string_is_false() {
+    let mut engine = Engine::new();
+    let value = engine.eval(r#""" || false"#).unwrap();
+    assert!(matches!(value, Value::Boolean(false)));
+}
+
+#[test]
+fn invalid_escape_is_an_error() {
+    let mut engine = Engine::new();
+    let error = engine.eval(r#""\q""#).unwrap_err();
+    assert_eq!(error.error_type, ErrorType::SyntaxError);
+}
+
+#[test]
+fn interpolation() {
+    let value = eval_string(
+        r#"
+        let name = "ego"
+        let age = 3
+        let message = "Hello {name}, you are {age + 1}"
+        message
+        "#,
+    );
+    assert_eq!(value, "Hello ego, you are 4");
+}
+
+#[test]
+fn interpolation_with_nested_strings_and_escaped_braces() {
+    let value = eval_string(
+        r#"
+        let user = { name: "ego" }
+        let message = "{ "name: " + user.name } \{{[1, 2]}\}"
+        message
+        "#,
+    );
+    assert_eq!(value, "name: ego {[1, 2]}");
+}
+
+#[test]
+fn interpolation_error_points_at_the_expression() {
+    let mut engine = Engine::new();
+    let error = engine.eval(r#"let s = "a {missing} b""#).unwrap_err();
+    let span = error.span.expect("the error has a span");
+    assert_eq!(error.error_type, ErrorType::ReferenceError);
+    assert_eq!((span.line, span.col), (1, 13));
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use common::{eval, eval_err, eval_number, eval_string};
+use ego::{Engine, ErrorType, Value};
+
+const POINT: &str = r#"
+    struct Point { x: number, y: number }
+
+    impl Point {
+      fn len(self) {
+        return (self.x ** 2 + self.y ** 2) ** 0.5
+      }
+      fn moved(self, dx) {
+        return Point { x: self.x + dx, y: self.y }
+      }
+      fn origin() {
+        return Point { x: 0, y: 0 }
+      }
+    }
+"#;
+
+#[test]
+fn fields_and_methods() {
+    let value = eval_string(&format!(
+        r#"{POINT}
+        let p = Point {{ x: 3, y: 4 }}
+        let q = p.moved(1)
+        "{{p.x}} {{p.len()}} {{q}} {{Point.origin()}}"
+        "#
+    ));
+    assert_eq!(value, "3 5 Point { x: 4, y: 4 } Point { x: 0, y: 0 }");
+}
+
+#[test]
+fn type_is_the_struct_name() {
+    let value = eval_string(&format!(
+        r#"{POINT}
+        let p = Point {{ x: 1, y: 2 }}
+        "{{type(p)}} {{type(Point)}}"
+        "#
+    ));
+    assert_eq!(value, "Point struct");
+}
+
+#[test]
+fn fields_can_be_assigned_and_compared() {
+    let value = eval(&format!(
+        r#"{POINT}
+        let p = Point {{ x: 1, y: 2 }}
+        p.y = 10
+        p == Point {{ x: 1, y: 10 }}
+        "#
+    ));
+    assert!(matches!(value, Value::Boolean(true)));
+}
+
+#[test]
+fn structs_declared_inside_functions() {
+    let value = eval(
+        r#"
+        fn make() {
+          struct Counter { count }
+          impl Counter {
+            fn next(self) {
+              return self.count + 1
+            }
+          }
+          return Counter { count: 1 }.next()
+        }
+        make()
+        "#,
+    );
+    assert!(matches!(value, Value::Number(n) if n == 2.0));
+}
+
+#[test]
+fn field_annotations_are_checked() {
+    let error = eval_err(&format!("{POINT}\nPoint {{ x: 1, y: \"2\" }}"));
+    assert_eq!(error.error_type, ErrorType::TypeError);
+    assert!(error.message.contains("'y'"), "{}", error.message);
+
+    // missing fields are nothing
+    let error = eval_err(&format!("{POINT}\nPoint {{ x: 1 }}"));
+    assert_eq!(error.error_type, ErrorType::TypeError);
+
+    let error = eval_err(&format!("{POINT}\nlet p = Point.origin()\np.x = true"));
+    assert_eq!(error.error_type, ErrorType::TypeError);
+}
+
+#[test]
+fn unknown_fields_are_errors() {
+    let error = eval_err(&format!("{POINT}\nPoint {{ x: 1, y: 2, z: 3 }}"));
+    assert_eq!(error.error_type, ErrorType::TypeError);
+
+    let error = eval_err(&format!("{POINT}\nPoint.origin().z"));
+    assert_eq!(error.error_type, ErrorType::TypeError);
+}
+
+#[test]
+fn check_reports_field_mismatches() {
+    let errors = Engine::new().check(&format!("{POINT}\nlet p = Point {{ x: \"1\", y: 2 }}"));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "Field 'x' of 'Point' expects a value of type 'number' but got 'string'"
+    );
+}
+
+#[test]
+fn braces_after_a_condition_open_its_body() {
+    let value = eval_number(
+        r#"
+        let running = false
+        let items = [1, 2]
+        let total = 0
+        while running {}
+        for item in items {
+            outer: for n in items {
+                total = total + item * n
+                break outer
+            }
+        }
+        total
+        "#,
+    );
+    assert_eq!(value, 3.0);
+}
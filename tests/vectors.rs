@@ -0,0 +1,89 @@
+mod common;
+
+use common::{eval, eval_err, eval_number, eval_string};
+use ego::{ErrorType, Value};
+
+#[test]
+fn vector_literals() {
+    let value = eval(r#"[1, "two", [3], nothing]"#);
+    match value {
+        Value::Vector(elements) => {
+            assert_eq!(elements.len(), 4);
+            assert!(matches!(elements[0], Value::Number(n) if n == 1.0));
+            assert!(matches!(&elements[1], Value::String(s) if s == "two"));
+            assert!(matches!(&elements[2], Value::Vector(inner) if inner.len() == 1));
+            assert!(matches!(elements[3], Value::Nothing));
+        }
+        value => panic!("expected a vector but got '{value}'"),
+    }
+}
+
+#[test]
+fn indexing_and_assignment() {
+    let value = eval_number(
+        r#"
+        let xs = [1, 2, [3, 4]]
+        xs[1] = 5
+        xs[2][0] = 6
+        xs[0] + xs[1] + xs[2][0] + xs[2][1]
+        "#,
+    );
+    assert_eq!(value, 16.0);
+}
+
+#[test]
+fn out_of_bounds_index_is_an_error() {
+    let error = eval_err(
+        r#"
+        let xs = [1, 2]
+        xs[2]
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::RangeError);
+
+    let error = eval_err(
+        r#"
+        let xs = [1, 2]
+        xs[5] = 3
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::RangeError);
+}
+
+#[test]
+fn negative_and_fractional_indexes_are_errors() {
+    let error = eval_err(
+        r#"
+        let xs = [1, 2]
+        xs[-1]
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::TypeError);
+
+    let error = eval_err(
+        r#"
+        let xs = [1, 2]
+        xs[0.5]
+        "#,
+    );
+    assert_eq!(error.error_type, ErrorType::TypeError);
+}
+
+#[test]
+fn vectors_are_copied_on_assignment() {
+    let value = eval_string(
+        r#"
+        let a = [1, 2]
+        let b = a
+        b[0] = 9
+
+        fn change(v) {
+            v[1] = 8
+            return v
+        }
+        let c = change(a)
+        "{a} {b} {c}"
+        "#,
+    );
+    assert_eq!(value, "[1, 2] [9, 2] [1, 8]");
+}